@@ -0,0 +1,41 @@
+const SAVE: u32 = 1;
+const SAVE_AS: u32 = 2;
+const COMMENT: u32 = 3;
+const UNCOMMENT: u32 = 4;
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let accelerators = [
+        ("Ctrl+S".parse()?, SAVE),
+        ("Ctrl+Shift+S".parse()?, SAVE_AS),
+        ("Ctrl+K, Ctrl+C".parse()?, COMMENT),
+        ("Ctrl+K, Ctrl+U".parse()?, UNCOMMENT),
+    ]
+    .into_iter()
+    .collect::<awita::AcceleratorTable>();
+    for (accelerator, id) in accelerators.iter() {
+        println!("{}: {}", id, accelerator);
+    }
+    let window = awita::Window::builder()
+        .title("awita accelerator")
+        .accelerators(accelerators)
+        .build()
+        .await?;
+    let mut accelerator = window.accelerator_receiver().await;
+    loop {
+        tokio::select! {
+            Ok(id) = accelerator.recv() => {
+                match id {
+                    SAVE => println!("save"),
+                    SAVE_AS => println!("save as"),
+                    COMMENT => println!("comment"),
+                    UNCOMMENT => println!("uncomment"),
+                    _ => {}
+                }
+            }
+            _ = awita::UiThread::join() => break,
+        }
+    }
+    awita::UiThread::maybe_unwind().await;
+    Ok(())
+}
@@ -0,0 +1,25 @@
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let theme = awita::system::theme();
+    println!("{:?}", theme);
+    let window = awita::Window::builder()
+        .title("awita appearance")
+        .dark_mode(theme.color_mode == awita::system::ColorMode::Dark)
+        .backdrop(awita::Backdrop::Mica)
+        .corner_preference(awita::CornerPreference::RoundSmall)
+        .border_color(awita::Rgba::new(0x00, 0x78, 0xd4, 0xff))
+        .build()
+        .await?;
+    let mut theme_changed = window.theme_changed_receiver().await;
+    loop {
+        tokio::select! {
+            Ok(theme) = theme_changed.recv() => {
+                println!("theme_changed: {:?}", theme);
+                window.set_dark_mode(theme.color_mode == awita::system::ColorMode::Dark);
+            }
+            _ = awita::UiThread::join() => break,
+        }
+    }
+    awita::UiThread::maybe_unwind().await;
+    Ok(())
+}
@@ -0,0 +1,40 @@
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let window = awita::Window::builder()
+        .title("awita borderless")
+        .style(awita::window::Style::borderless())
+        .hit_test(
+            awita::HitTestRegions::new()
+                .resize_border(8)
+                .area(
+                    awita::Physical(awita::Rect::new((0, 0), (48, 48))),
+                    awita::HitArea::CloseButton,
+                ),
+        )
+        .build()
+        .await?;
+    let mut mouse_input = window.mouse_input_receiver().await;
+    let mut moved = window.moved_receiver().await;
+    let mut resized = window.resized_receiver().await;
+    loop {
+        tokio::select! {
+            Ok(data) = mouse_input.recv() => {
+                println!("mouse_input: {:?}", data);
+                let left = data.button == awita::MouseButton::Left;
+                let pressed = data.button_state == awita::ButtonState::Pressed;
+                if left && pressed {
+                    window.begin_drag_move();
+                }
+            }
+            Ok(data) = moved.recv() => {
+                println!("moved: {:?}", data);
+            }
+            Ok(data) = resized.recv() => {
+                println!("resized: {:?}", data);
+            }
+            _ = awita::UiThread::join() => break,
+        }
+    }
+    awita::UiThread::maybe_unwind().await;
+    Ok(())
+}
@@ -14,6 +14,7 @@ async fn main() -> anyhow::Result<()> {
     let mut mouse_h_wheel = window.mouse_h_wheel_receiver().await;
     let mut key_input = window.key_input_receiver().await;
     let mut char_input = window.char_input_receiver().await;
+    let mut text_input = window.text_input_receiver().await;
     let mut moved = window.moved_receiver().await;
     let mut resizing = window.resizing_receiver().await;
     let mut resized = window.resized_receiver().await;
@@ -68,6 +69,9 @@ async fn main() -> anyhow::Result<()> {
                     println!("char_input: {}", c);
                 }
             }
+            Ok(text) = text_input.recv() => {
+                println!("text_input: {:?}", text);
+            }
             Ok(data) = moved.recv() => {
                 println!("moved: {:?}", data);
             }
@@ -0,0 +1,21 @@
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let window = awita::Window::builder()
+        .title("awita hotkey")
+        .build()
+        .await?;
+    let mut capture =
+        awita::UiThread::register_hotkey("Ctrl+Shift+F9".parse::<awita::KeyStroke>()?).await?;
+    println!("press {} anywhere", capture.stroke());
+    loop {
+        tokio::select! {
+            Ok(stroke) = capture.recv() => {
+                println!("hotkey: {}", stroke);
+                window.show();
+            }
+            _ = awita::UiThread::join() => break,
+        }
+    }
+    awita::UiThread::maybe_unwind().await;
+    Ok(())
+}
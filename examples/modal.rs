@@ -0,0 +1,51 @@
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let window = awita::Window::builder()
+        .title("awita modal")
+        .build()
+        .await?;
+    let mut mouse_input = window.mouse_input_receiver().await;
+    loop {
+        tokio::select! {
+            Ok(data) = mouse_input.recv() => {
+                if data.button_state != awita::ButtonState::Released {
+                    continue;
+                }
+                let dialog = awita::Window::builder()
+                    .title("awita modal dialog")
+                    .style(awita::window::Style::dialog())
+                    .size(awita::Logical(awita::Size::new(320, 240)))
+                    .visible(false)
+                    .owner(&window)
+                    .build()
+                    .await?;
+                let mut dialog_input = dialog.mouse_input_receiver().await;
+                let modal = dialog.run_modal(&window);
+                tokio::pin!(modal);
+                loop {
+                    tokio::select! {
+                        ret = &mut modal => {
+                            ret?;
+                            break;
+                        }
+                        Ok(data) = dialog_input.recv() => {
+                            if data.button_state != awita::ButtonState::Released {
+                                continue;
+                            }
+                            match data.button {
+                                awita::MouseButton::Left => dialog.hide(),
+                                awita::MouseButton::Right => dialog.close(),
+                                _ => {}
+                            }
+                        }
+                    }
+                }
+                println!("dialog ended");
+                dialog.close();
+            }
+            _ = awita::UiThread::join() => break,
+        }
+    }
+    awita::UiThread::maybe_unwind().await;
+    Ok(())
+}
@@ -0,0 +1,25 @@
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    for monitor in awita::UiThread::monitors().await {
+        println!("{:?}", monitor);
+    }
+    let window = awita::Window::builder()
+        .title("awita monitors")
+        .build()
+        .await?;
+    let mut monitors_changed = awita::UiThread::monitors_changed_receiver().await;
+    let mut moved = window.moved_receiver().await;
+    loop {
+        tokio::select! {
+            Ok(monitors) = monitors_changed.recv() => {
+                println!("monitors_changed: {:?}", monitors);
+            }
+            Ok(_) = moved.recv() => {
+                println!("current_monitor: {:?}", window.current_monitor().await?.name);
+            }
+            _ = awita::UiThread::join() => break,
+        }
+    }
+    awita::UiThread::maybe_unwind().await;
+    Ok(())
+}
@@ -0,0 +1,42 @@
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let window = awita::Window::builder()
+        .title("awita popup")
+        .build()
+        .await?;
+    let mut mouse_input = window.mouse_input_receiver().await;
+    let mut popup: Option<awita::Window> = None;
+    loop {
+        tokio::select! {
+            Ok(data) = mouse_input.recv() => {
+                if data.button_state != awita::ButtonState::Released {
+                    continue;
+                }
+                if let Some(popup) = popup.take() {
+                    popup.close();
+                }
+                let position = window.client_to_screen(data.mouse_state.position).await?;
+                let anchor = awita::Screen(awita::Rect::new((position.x, position.y), (1, 1)));
+                let monitors = awita::UiThread::monitors().await;
+                let rect = awita::Positioner::new(awita::Physical(awita::Size::new(240, 320)))
+                    .offset(awita::Screen(awita::Vector::new(0, 4)))
+                    .place_on(anchor, &monitors);
+                let window = awita::Window::builder()
+                    .title("awita popup menu")
+                    .style(awita::window::Style::borderless())
+                    .position(awita::Screen(rect.origin))
+                    .size(awita::Physical(awita::Size::new(
+                        rect.size.width as u32,
+                        rect.size.height as u32,
+                    )))
+                    .owner(&window)
+                    .build()
+                    .await?;
+                popup = Some(window);
+            }
+            _ = awita::UiThread::join() => break,
+        }
+    }
+    awita::UiThread::maybe_unwind().await;
+    Ok(())
+}
@@ -0,0 +1,30 @@
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let window = awita::Window::builder()
+        .title("awita shaped")
+        .style(awita::window::Style::borderless())
+        .size(awita::Logical(awita::Size::new(256, 256)))
+        .build()
+        .await?;
+    let size = window.inner_size().await?;
+    window.set_shape(awita::Region::ellipse(awita::Physical(awita::Rect::new(
+        (0, 0),
+        (size.width as i32, size.height as i32),
+    ))));
+    let mut mouse_input = window.mouse_input_receiver().await;
+    loop {
+        tokio::select! {
+            Ok(data) = mouse_input.recv() => {
+                let pressed = data.button_state == awita::ButtonState::Pressed;
+                match data.button {
+                    awita::MouseButton::Left if pressed => window.begin_drag_move(),
+                    awita::MouseButton::Right if pressed => window.close(),
+                    _ => {}
+                }
+            }
+            _ = awita::UiThread::join() => break,
+        }
+    }
+    awita::UiThread::maybe_unwind().await;
+    Ok(())
+}
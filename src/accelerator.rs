@@ -0,0 +1,556 @@
+use super::*;
+use std::borrow::Cow;
+use std::convert::TryFrom;
+
+#[derive(Clone, PartialEq, Eq, Debug, thiserror::Error)]
+pub enum ParseAcceleratorError {
+    #[error("empty accelerator")]
+    Empty,
+    #[error("unknown modifier: {0}")]
+    UnknownModifier(String),
+    #[error("unknown key: {0}")]
+    UnknownKey(String),
+    #[error("missing key: {0}")]
+    MissingKey(String),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeyStroke {
+    pub ctrl: bool,
+    pub shift: bool,
+    pub alt: bool,
+    pub win: bool,
+    pub key: VirtualKey,
+}
+
+impl KeyStroke {
+    #[inline]
+    pub fn new(key: VirtualKey) -> Self {
+        Self {
+            ctrl: false,
+            shift: false,
+            alt: false,
+            win: false,
+            key,
+        }
+    }
+
+    #[inline]
+    pub fn ctrl(mut self) -> Self {
+        self.ctrl = true;
+        self
+    }
+
+    #[inline]
+    pub fn shift(mut self) -> Self {
+        self.shift = true;
+        self
+    }
+
+    #[inline]
+    pub fn alt(mut self) -> Self {
+        self.alt = true;
+        self
+    }
+
+    #[inline]
+    pub fn win(mut self) -> Self {
+        self.win = true;
+        self
+    }
+
+    pub fn from_key(vkey: VirtualKeyCode, modifiers: Modifiers) -> Option<Self> {
+        let key = VirtualKey::try_from(vkey).ok()?;
+        if is_modifier_key(key) {
+            return None;
+        }
+        Some(Self {
+            ctrl: modifiers.ctrl(),
+            shift: modifiers.shift(),
+            alt: modifiers.alt(),
+            win: modifiers.win(),
+            key,
+        })
+    }
+
+    pub fn to_canonical_string(&self) -> String {
+        let mut s = String::new();
+        self.write(&mut s, |key| Cow::Borrowed(canonical_name(key)))
+            .ok();
+        s
+    }
+
+    fn write(
+        &self,
+        f: &mut impl std::fmt::Write,
+        name: impl Fn(VirtualKey) -> Cow<'static, str>,
+    ) -> std::fmt::Result {
+        if self.ctrl {
+            write!(f, "{}+", name(VirtualKey::Ctrl))?;
+        }
+        if self.shift {
+            write!(f, "{}+", name(VirtualKey::Shift))?;
+        }
+        if self.alt {
+            write!(f, "{}+", name(VirtualKey::Alt))?;
+        }
+        if self.win {
+            f.write_str("Win+")?;
+        }
+        f.write_str(&name(self.key))
+    }
+}
+
+impl From<VirtualKey> for KeyStroke {
+    #[inline]
+    fn from(src: VirtualKey) -> Self {
+        Self::new(src)
+    }
+}
+
+impl std::str::FromStr for KeyStroke {
+    type Err = ParseAcceleratorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseAcceleratorError::Empty);
+        }
+        let (modifiers, key) = if s == "+" {
+            ("", "+")
+        } else if let Some(modifiers) = s.strip_suffix("++") {
+            (modifiers, "+")
+        } else {
+            s.rsplit_once('+').unwrap_or(("", s))
+        };
+        let key = key.trim();
+        if key.is_empty() {
+            return Err(ParseAcceleratorError::MissingKey(s.into()));
+        }
+        let key = parse_key(key).ok_or_else(|| ParseAcceleratorError::UnknownKey(key.into()))?;
+        if is_modifier_key(key) {
+            return Err(ParseAcceleratorError::MissingKey(s.into()));
+        }
+        let mut stroke = KeyStroke::new(key);
+        for modifier in modifiers.split('+').map(str::trim) {
+            match modifier.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => stroke.ctrl = true,
+                "shift" => stroke.shift = true,
+                "alt" => stroke.alt = true,
+                "win" | "meta" | "super" => stroke.win = true,
+                "" if modifiers.is_empty() => {}
+                _ => return Err(ParseAcceleratorError::UnknownModifier(modifier.into())),
+            }
+        }
+        Ok(stroke)
+    }
+}
+
+/// Uses the keyboard layout's localized key names, so the output is not guaranteed to parse back.
+/// Use `to_canonical_string` for a parseable form.
+impl std::fmt::Display for KeyStroke {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.write(f, key_name)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Accelerator {
+    strokes: Vec<KeyStroke>,
+}
+
+impl Accelerator {
+    #[inline]
+    pub fn new(stroke: impl Into<KeyStroke>) -> Self {
+        Self {
+            strokes: vec![stroke.into()],
+        }
+    }
+
+    #[inline]
+    pub fn then(mut self, stroke: impl Into<KeyStroke>) -> Self {
+        self.strokes.push(stroke.into());
+        self
+    }
+
+    #[inline]
+    pub fn strokes(&self) -> &[KeyStroke] {
+        &self.strokes
+    }
+
+    #[inline]
+    pub fn is_chord(&self) -> bool {
+        self.strokes.len() > 1
+    }
+
+    pub fn to_canonical_string(&self) -> String {
+        let mut s = String::new();
+        self.write(&mut s, |key| Cow::Borrowed(canonical_name(key)))
+            .ok();
+        s
+    }
+
+    fn write(
+        &self,
+        f: &mut impl std::fmt::Write,
+        name: impl Fn(VirtualKey) -> Cow<'static, str> + Copy,
+    ) -> std::fmt::Result {
+        for (i, stroke) in self.strokes.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            stroke.write(f, name)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<KeyStroke> for Accelerator {
+    #[inline]
+    fn from(src: KeyStroke) -> Self {
+        Self::new(src)
+    }
+}
+
+impl std::str::FromStr for Accelerator {
+    type Err = ParseAcceleratorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let strokes = s
+            .split(',')
+            .map(str::parse::<KeyStroke>)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { strokes })
+    }
+}
+
+/// Uses the keyboard layout's localized key names, so the output is not guaranteed to parse back.
+/// Use `to_canonical_string` for a parseable form.
+impl std::fmt::Display for Accelerator {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.write(f, key_name)
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct AcceleratorTable {
+    entries: Vec<(Accelerator, u32)>,
+    pending: Vec<KeyStroke>,
+}
+
+impl AcceleratorTable {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, accelerator: Accelerator, id: u32) -> Option<u32> {
+        self.pending.clear();
+        if let Some(entry) = self.entries.iter_mut().find(|(a, _)| *a == accelerator) {
+            return Some(std::mem::replace(&mut entry.1, id));
+        }
+        self.entries.push((accelerator, id));
+        None
+    }
+
+    pub fn remove(&mut self, accelerator: &Accelerator) -> Option<u32> {
+        self.pending.clear();
+        let i = self.entries.iter().position(|(a, _)| a == accelerator)?;
+        Some(self.entries.remove(i).1)
+    }
+
+    #[inline]
+    pub fn get(&self, accelerator: &Accelerator) -> Option<u32> {
+        self.entries
+            .iter()
+            .find(|(a, _)| a == accelerator)
+            .map(|(_, id)| *id)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&Accelerator, u32)> {
+        self.entries.iter().map(|(a, id)| (a, *id))
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    #[inline]
+    pub fn is_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    #[inline]
+    pub fn reset(&mut self) {
+        self.pending.clear();
+    }
+
+    pub fn process(&mut self, vkey: VirtualKeyCode, modifiers: Modifiers) -> Option<u32> {
+        let key = VirtualKey::try_from(vkey).ok();
+        if matches!(key, Some(key) if is_modifier_key(key)) {
+            return None;
+        }
+        let stroke = match KeyStroke::from_key(vkey, modifiers) {
+            Some(stroke) => stroke,
+            None => {
+                self.pending.clear();
+                return None;
+            }
+        };
+        let retry = self.is_pending();
+        self.pending.push(stroke);
+        if let Some(id) = self.matched() {
+            self.pending.clear();
+            return Some(id);
+        }
+        if self.has_prefix() {
+            return None;
+        }
+        self.pending.clear();
+        if retry {
+            self.process(vkey, modifiers)
+        } else {
+            None
+        }
+    }
+
+    fn matched(&self) -> Option<u32> {
+        self.entries
+            .iter()
+            .find(|(a, _)| a.strokes == self.pending)
+            .map(|(_, id)| *id)
+    }
+
+    fn has_prefix(&self) -> bool {
+        self.entries
+            .iter()
+            .any(|(a, _)| a.strokes.starts_with(&self.pending))
+    }
+}
+
+impl std::iter::FromIterator<(Accelerator, u32)> for AcceleratorTable {
+    fn from_iter<I: IntoIterator<Item = (Accelerator, u32)>>(iter: I) -> Self {
+        let mut table = Self::new();
+        for (accelerator, id) in iter {
+            table.insert(accelerator, id);
+        }
+        table
+    }
+}
+
+fn is_modifier_key(key: VirtualKey) -> bool {
+    matches!(
+        key,
+        VirtualKey::Shift
+            | VirtualKey::LShift
+            | VirtualKey::RShift
+            | VirtualKey::Ctrl
+            | VirtualKey::LCtrl
+            | VirtualKey::RCtrl
+            | VirtualKey::Alt
+            | VirtualKey::LAlt
+            | VirtualKey::RAlt
+            | VirtualKey::LWin
+            | VirtualKey::RWin
+    )
+}
+
+fn parse_key(s: &str) -> Option<VirtualKey> {
+    let alias = match s.to_ascii_lowercase().as_str() {
+        "escape" => Some(VirtualKey::Esc),
+        "return" => Some(VirtualKey::Enter),
+        "del" => Some(VirtualKey::Delete),
+        "ins" => Some(VirtualKey::Insert),
+        "pgup" => Some(VirtualKey::PageUp),
+        "pgdn" => Some(VirtualKey::PageDown),
+        "arrowleft" => Some(VirtualKey::Left),
+        "arrowup" => Some(VirtualKey::Up),
+        "arrowright" => Some(VirtualKey::Right),
+        "arrowdown" => Some(VirtualKey::Down),
+        "menu" => Some(VirtualKey::Apps),
+        "plus" | "+" => Some(VirtualKey::OemPlus),
+        "minus" | "-" => Some(VirtualKey::OemMinus),
+        "comma" | "," => Some(VirtualKey::OemComma),
+        "period" | "." => Some(VirtualKey::OemPeriod),
+        _ => None,
+    };
+    if alias.is_some() {
+        return alias;
+    }
+    if let Ok(key) = s.parse::<VirtualKey>() {
+        return Some(key);
+    }
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => VirtualKey::try_from(VirtualKeyCode::from_char(c)?).ok(),
+        _ => None,
+    }
+}
+
+fn canonical_name(key: VirtualKey) -> &'static str {
+    match key {
+        VirtualKey::OemPlus => "Plus",
+        VirtualKey::OemMinus => "Minus",
+        VirtualKey::OemComma => "Comma",
+        VirtualKey::OemPeriod => "Period",
+        _ => key.as_str(),
+    }
+}
+
+fn key_name(key: VirtualKey) -> Cow<'static, str> {
+    match key {
+        VirtualKey::OemPlus
+        | VirtualKey::OemMinus
+        | VirtualKey::OemComma
+        | VirtualKey::OemPeriod => Cow::Borrowed(canonical_name(key)),
+        _ => match utility::key_name(key) {
+            Some(name) => Cow::Owned(name),
+            None => Cow::Borrowed(canonical_name(key)),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mods(modifiers: &[Modifier]) -> Modifiers {
+        Modifiers::new(modifiers)
+    }
+
+    #[test]
+    fn parse_stroke() {
+        let stroke = "Ctrl+Shift+S".parse::<KeyStroke>().unwrap();
+        assert!(stroke == KeyStroke::new(VirtualKey::S).ctrl().shift());
+        let stroke = "alt + f4".parse::<KeyStroke>().unwrap();
+        assert!(stroke == KeyStroke::new(VirtualKey::F4).alt());
+        let stroke = "Control+Win+pgup".parse::<KeyStroke>().unwrap();
+        assert!(stroke == KeyStroke::new(VirtualKey::PageUp).ctrl().win());
+        assert!("Ctrl++".parse::<KeyStroke>() == Ok(KeyStroke::new(VirtualKey::OemPlus).ctrl()));
+        assert!("+".parse::<KeyStroke>() == Ok(KeyStroke::new(VirtualKey::OemPlus)));
+        assert!("z".parse::<KeyStroke>() == Ok(KeyStroke::new(VirtualKey::Z)));
+        assert!("9".parse::<KeyStroke>() == Ok(KeyStroke::new(VirtualKey::_9)));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!("".parse::<KeyStroke>() == Err(ParseAcceleratorError::Empty));
+        assert!(matches!(
+            "Ctrl+Shift".parse::<KeyStroke>(),
+            Err(ParseAcceleratorError::MissingKey(_))
+        ));
+        assert!(matches!(
+            "Ctrl+".parse::<KeyStroke>(),
+            Err(ParseAcceleratorError::MissingKey(_))
+        ));
+        assert!(matches!(
+            "Hyper+S".parse::<KeyStroke>(),
+            Err(ParseAcceleratorError::UnknownModifier(_))
+        ));
+        assert!(matches!(
+            "Ctrl+Foo".parse::<KeyStroke>(),
+            Err(ParseAcceleratorError::UnknownKey(_))
+        ));
+        assert!(matches!(
+            "Ctrl+K,".parse::<Accelerator>(),
+            Err(ParseAcceleratorError::Empty)
+        ));
+    }
+
+    #[test]
+    fn parse_chord() {
+        let a = "Ctrl+K, Ctrl+C".parse::<Accelerator>().unwrap();
+        assert!(a.is_chord());
+        assert!(
+            a == Accelerator::new(KeyStroke::new(VirtualKey::K).ctrl())
+                .then(KeyStroke::new(VirtualKey::C).ctrl())
+        );
+    }
+
+    #[test]
+    fn canonical_round_trip() {
+        for s in [
+            "Ctrl+Shift+S",
+            "Alt+F4",
+            "Ctrl+K, Ctrl+C",
+            "Ctrl+Plus",
+            "Win+Left",
+            "Esc",
+        ] {
+            let a = s.parse::<Accelerator>().unwrap();
+            assert!(a.to_canonical_string() == s);
+            assert!(a.to_canonical_string().parse::<Accelerator>() == Ok(a));
+        }
+        let stroke = KeyStroke::new(VirtualKey::OemComma).ctrl();
+        assert!(stroke.to_canonical_string() == "Ctrl+Comma");
+        assert!(stroke.to_canonical_string().parse::<KeyStroke>() == Ok(stroke));
+    }
+
+    #[test]
+    fn table_matches_single_strokes() {
+        let mut table = AcceleratorTable::new();
+        table.insert("Ctrl+S".parse().unwrap(), 1);
+        table.insert("Ctrl+Shift+S".parse().unwrap(), 2);
+        assert!(table.process(VirtualKey::S.into(), mods(&[Modifier::LCtrl])) == Some(1));
+        assert!(
+            table.process(
+                VirtualKey::S.into(),
+                mods(&[Modifier::RCtrl, Modifier::LShift])
+            ) == Some(2)
+        );
+        assert!(table
+            .process(VirtualKey::S.into(), Modifiers::default())
+            .is_none());
+        assert!(table
+            .process(
+                VirtualKey::S.into(),
+                mods(&[Modifier::LCtrl, Modifier::LAlt])
+            )
+            .is_none());
+    }
+
+    #[test]
+    fn table_matches_chords() {
+        let mut table = [
+            ("Ctrl+K, Ctrl+C".parse().unwrap(), 1),
+            ("Ctrl+K, Ctrl+U".parse().unwrap(), 2),
+            ("Ctrl+S".parse().unwrap(), 3),
+        ]
+        .into_iter()
+        .collect::<AcceleratorTable>();
+        let ctrl = mods(&[Modifier::LCtrl]);
+        assert!(table.process(VirtualKey::K.into(), ctrl).is_none());
+        assert!(table.is_pending());
+        assert!(table.process(VirtualKey::LCtrl.into(), ctrl).is_none());
+        assert!(table.is_pending());
+        assert!(table.process(VirtualKey::U.into(), ctrl) == Some(2));
+        assert!(!table.is_pending());
+        assert!(table.process(VirtualKey::K.into(), ctrl).is_none());
+        assert!(table.process(VirtualKey::X.into(), ctrl).is_none());
+        assert!(!table.is_pending());
+        assert!(table.process(VirtualKey::C.into(), ctrl).is_none());
+        assert!(table.process(VirtualKey::K.into(), ctrl).is_none());
+        assert!(table.process(VirtualKey::S.into(), ctrl) == Some(3));
+    }
+
+    #[test]
+    fn table_insert_and_remove() {
+        let mut table = AcceleratorTable::new();
+        let a = "Ctrl+O".parse::<Accelerator>().unwrap();
+        assert!(table.insert(a.clone(), 1).is_none());
+        assert!(table.insert(a.clone(), 2) == Some(1));
+        assert!(table.get(&a) == Some(2));
+        assert!(table.iter().count() == 1);
+        assert!(table.remove(&a) == Some(2));
+        assert!(table.is_empty());
+        assert!(table
+            .process(VirtualKey::O.into(), mods(&[Modifier::LCtrl]))
+            .is_none());
+    }
+}
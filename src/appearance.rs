@@ -0,0 +1,199 @@
+use windows::Win32::{Foundation::*, Graphics::Dwm::*, UI::Controls::MARGINS};
+
+pub use gecl::Rgba;
+
+const DWMWA_USE_IMMERSIVE_DARK_MODE_BEFORE_20H1: DWMWINDOWATTRIBUTE = 19;
+const DWMWA_SYSTEMBACKDROP_TYPE: DWMWINDOWATTRIBUTE = 38;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Backdrop {
+    Auto,
+    None,
+    Mica,
+    Acrylic,
+    Tabbed,
+}
+
+impl Backdrop {
+    #[inline]
+    fn value(self) -> u32 {
+        match self {
+            Self::Auto => 0,
+            Self::None => 1,
+            Self::Mica => 2,
+            Self::Acrylic => 3,
+            Self::Tabbed => 4,
+        }
+    }
+
+    #[inline]
+    fn extends_frame(self) -> bool {
+        matches!(self, Self::Mica | Self::Acrylic | Self::Tabbed)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CornerPreference {
+    Default,
+    Round,
+    RoundSmall,
+    Square,
+}
+
+impl CornerPreference {
+    #[inline]
+    fn value(self) -> DWM_WINDOW_CORNER_PREFERENCE {
+        match self {
+            Self::Default => DWMWCP_DEFAULT,
+            Self::Round => DWMWCP_ROUND,
+            Self::RoundSmall => DWMWCP_ROUNDSMALL,
+            Self::Square => DWMWCP_DONOTROUND,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FrameColor {
+    Default,
+    None,
+    Color(Rgba<u8>),
+}
+
+impl FrameColor {
+    #[inline]
+    fn value(self) -> u32 {
+        match self {
+            Self::Default => DWMWA_COLOR_DEFAULT,
+            Self::None => DWMWA_COLOR_NONE,
+            Self::Color(c) => c.r as u32 | (c.g as u32) << 8 | (c.b as u32) << 16,
+        }
+    }
+}
+
+impl From<Rgba<u8>> for FrameColor {
+    #[inline]
+    fn from(src: Rgba<u8>) -> Self {
+        Self::Color(src)
+    }
+}
+
+#[derive(Clone, Copy, Default, Debug)]
+pub(crate) struct Appearance {
+    pub dark_mode: Option<bool>,
+    pub backdrop: Option<Backdrop>,
+    pub corner_preference: Option<CornerPreference>,
+    pub caption_color: Option<FrameColor>,
+    pub border_color: Option<FrameColor>,
+    pub text_color: Option<FrameColor>,
+}
+
+impl Appearance {
+    pub fn apply(&self, hwnd: HWND, frame_extended: &mut bool) {
+        if let Some(dark_mode) = self.dark_mode {
+            set_dark_mode(hwnd, dark_mode);
+        }
+        if let Some(backdrop) = self.backdrop {
+            set_backdrop(hwnd, backdrop, frame_extended).ok();
+        }
+        if let Some(corner_preference) = self.corner_preference {
+            set_corner_preference(hwnd, corner_preference);
+        }
+        if let Some(color) = self.caption_color {
+            set_caption_color(hwnd, color);
+        }
+        if let Some(color) = self.border_color {
+            set_border_color(hwnd, color);
+        }
+        if let Some(color) = self.text_color {
+            set_text_color(hwnd, color);
+        }
+    }
+}
+
+fn set_attribute<T>(hwnd: HWND, attr: DWMWINDOWATTRIBUTE, value: &T) -> windows::core::Result<()> {
+    unsafe {
+        DwmSetWindowAttribute(
+            hwnd,
+            attr,
+            value as *const T as _,
+            std::mem::size_of::<T>() as _,
+        )
+    }
+}
+
+pub(crate) fn set_dark_mode(hwnd: HWND, flag: bool) {
+    let value = BOOL::from(flag);
+    if set_attribute(hwnd, DWMWA_USE_IMMERSIVE_DARK_MODE, &value).is_err() {
+        set_attribute(hwnd, DWMWA_USE_IMMERSIVE_DARK_MODE_BEFORE_20H1, &value).ok();
+    }
+}
+
+pub(crate) fn set_backdrop(
+    hwnd: HWND,
+    backdrop: Backdrop,
+    frame_extended: &mut bool,
+) -> windows::core::Result<()> {
+    if backdrop.extends_frame() != *frame_extended {
+        let margins = if backdrop.extends_frame() {
+            MARGINS {
+                cxLeftWidth: -1,
+                cxRightWidth: -1,
+                cyTopHeight: -1,
+                cyBottomHeight: -1,
+            }
+        } else {
+            MARGINS::default()
+        };
+        unsafe {
+            DwmExtendFrameIntoClientArea(hwnd, &margins)?;
+        }
+        *frame_extended = backdrop.extends_frame();
+    }
+    set_attribute(hwnd, DWMWA_SYSTEMBACKDROP_TYPE, &backdrop.value())
+}
+
+pub(crate) fn set_corner_preference(hwnd: HWND, corner_preference: CornerPreference) {
+    set_attribute(
+        hwnd,
+        DWMWA_WINDOW_CORNER_PREFERENCE,
+        &corner_preference.value(),
+    )
+    .ok();
+}
+
+pub(crate) fn set_caption_color(hwnd: HWND, color: FrameColor) {
+    set_attribute(hwnd, DWMWA_CAPTION_COLOR, &color.value()).ok();
+}
+
+pub(crate) fn set_border_color(hwnd: HWND, color: FrameColor) {
+    set_attribute(hwnd, DWMWA_BORDER_COLOR, &color.value()).ok();
+}
+
+pub(crate) fn set_text_color(hwnd: HWND, color: FrameColor) {
+    set_attribute(hwnd, DWMWA_TEXT_COLOR, &color.value()).ok();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backdrop_extends_frame() {
+        assert!(!Backdrop::Auto.extends_frame());
+        assert!(!Backdrop::None.extends_frame());
+        assert!(Backdrop::Mica.extends_frame());
+        assert!(Backdrop::Acrylic.extends_frame());
+        assert!(Backdrop::Tabbed.extends_frame());
+    }
+
+    #[test]
+    fn frame_color_value() {
+        assert!(FrameColor::Default.value() == 0xffffffff);
+        assert!(FrameColor::None.value() == 0xfffffffe);
+        let color = FrameColor::from(Rgba::new(0x12, 0x34, 0x56, 0xff));
+        assert!(color.value() == 0x00563412);
+    }
+}
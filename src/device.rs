@@ -172,130 +172,457 @@ impl std::ops::BitOr<MouseButtons> for MouseButtons {
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum Modifier {
+    LShift = 1 << 0,
+    RShift = 1 << 1,
+    LCtrl = 1 << 2,
+    RCtrl = 1 << 3,
+    LAlt = 1 << 4,
+    RAlt = 1 << 5,
+    LWin = 1 << 6,
+    RWin = 1 << 7,
+}
+
+impl Modifier {
+    const ALL: [Modifier; 8] = [
+        Modifier::LShift,
+        Modifier::RShift,
+        Modifier::LCtrl,
+        Modifier::RCtrl,
+        Modifier::LAlt,
+        Modifier::RAlt,
+        Modifier::LWin,
+        Modifier::RWin,
+    ];
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Modifiers(u32);
+
+impl Modifiers {
+    #[inline]
+    pub fn new(modifiers: &[Modifier]) -> Self {
+        Self(modifiers.iter().fold(0, |bits, m| bits | *m as u32))
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    #[inline]
+    pub fn contains(&self, modifier: Modifier) -> bool {
+        let modifier = modifier as u32;
+        (self.0 & modifier) == modifier
+    }
+
+    #[inline]
+    pub fn shift(&self) -> bool {
+        self.contains(Modifier::LShift) || self.contains(Modifier::RShift)
+    }
+
+    #[inline]
+    pub fn ctrl(&self) -> bool {
+        self.contains(Modifier::LCtrl) || self.contains(Modifier::RCtrl)
+    }
+
+    #[inline]
+    pub fn alt(&self) -> bool {
+        self.contains(Modifier::LAlt) || self.contains(Modifier::RAlt)
+    }
+
+    #[inline]
+    pub fn win(&self) -> bool {
+        self.contains(Modifier::LWin) || self.contains(Modifier::RWin)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = Modifier> + '_ {
+        Modifier::ALL.into_iter().filter(|m| self.contains(*m))
+    }
+}
+
+impl From<Modifier> for Modifiers {
+    #[inline]
+    fn from(src: Modifier) -> Self {
+        Self(src as u32)
+    }
+}
+
+impl std::fmt::Debug for Modifiers {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_tuple("Modifiers")
+            .field(&self.iter().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl std::ops::BitOr<Modifier> for Modifier {
+    type Output = Modifiers;
+
+    fn bitor(self, rhs: Modifier) -> Modifiers {
+        Modifiers(self as u32 | rhs as u32)
+    }
+}
+
+impl std::ops::BitOr<Modifier> for Modifiers {
+    type Output = Modifiers;
+
+    fn bitor(self, rhs: Modifier) -> Modifiers {
+        Modifiers(self.0 | rhs as u32)
+    }
+}
+
+impl std::ops::BitOr<Modifiers> for Modifier {
+    type Output = Modifiers;
+
+    fn bitor(self, rhs: Modifiers) -> Modifiers {
+        Modifiers(self as u32 | rhs.0)
+    }
+}
+
+impl std::ops::BitOr<Modifiers> for Modifiers {
+    type Output = Modifiers;
+
+    fn bitor(self, rhs: Modifiers) -> Modifiers {
+        Modifiers(self.0 | rhs.0)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LockState {
+    pub caps_lock: bool,
+    pub num_lock: bool,
+    pub scroll_lock: bool,
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct KeyboardState([u8; 256]);
+
+impl KeyboardState {
+    #[inline]
+    pub(crate) fn new(state: [u8; 256]) -> Self {
+        Self(state)
+    }
+
+    #[inline]
+    pub fn is_down(&self, key: VirtualKey) -> bool {
+        self.0[key as usize] & 0x80 != 0
+    }
+
+    #[inline]
+    pub fn is_toggled(&self, key: VirtualKey) -> bool {
+        self.0[key as usize] & 0x01 != 0
+    }
+
+    #[inline]
+    pub fn key_state(&self, key: VirtualKey) -> ButtonState {
+        if self.is_down(key) {
+            ButtonState::Pressed
+        } else {
+            ButtonState::Released
+        }
+    }
+
+    pub fn keys_down(&self) -> impl Iterator<Item = VirtualKey> + '_ {
+        VirtualKey::ALL
+            .iter()
+            .copied()
+            .filter(move |key| self.is_down(*key))
+    }
+
+    pub fn mouse_buttons(&self) -> MouseButtons {
+        let buttons = [
+            (VirtualKey::LButton, MouseButton::Left),
+            (VirtualKey::RButton, MouseButton::Right),
+            (VirtualKey::MButton, MouseButton::Middle),
+            (VirtualKey::XButton1, MouseButton::Ex0),
+            (VirtualKey::XButton2, MouseButton::Ex1),
+        ];
+        MouseButtons::from(
+            buttons
+                .iter()
+                .filter(|(key, _)| self.is_down(*key))
+                .fold(0, |bits, (_, button)| bits | *button as u32),
+        )
+    }
+
+    #[inline]
+    pub fn lock_state(&self) -> LockState {
+        LockState {
+            caps_lock: self.is_toggled(VirtualKey::CapsLock),
+            num_lock: self.is_toggled(VirtualKey::NumLock),
+            scroll_lock: self.is_toggled(VirtualKey::ScrollLock),
+        }
+    }
+}
+
+impl std::fmt::Debug for KeyboardState {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_tuple("KeyboardState")
+            .field(&self.keys_down().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct MouseState {
     pub position: PhysicalPoint<i32>,
     pub buttons: MouseButtons,
+    pub modifiers: Modifiers,
+    pub lock_state: LockState,
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
-#[repr(u32)]
-pub enum VirtualKey {
-    BackSpace = 0x08,
-    Tab = 0x09,
-    Enter = 0x0d,
-    Shift = 0x10,
-    Ctrl = 0x11,
-    Alt = 0x12,
-    Pause = 0x13,
-    CapsLock = 0x14,
-    Esc = 0x1b,
-    Space = 0x20,
-    PageUp = 0x21,
-    PageDown = 0x22,
-    End = 0x23,
-    Home = 0x24,
-    Left = 0x25,
-    Up = 0x26,
-    Right = 0x27,
-    Down = 0x28,
-    PrintScreen = 0x2c,
-    Insert = 0x2d,
-    Delete = 0x2e,
-    _0 = 0x30,
-    _1,
-    _2,
-    _3,
-    _4,
-    _5,
-    _6,
-    _7,
-    _8,
-    _9,
-    A = 0x41,
-    B,
-    C,
-    D,
-    E,
-    F,
-    G,
-    H,
-    I,
-    J,
-    K,
-    L,
-    M,
-    N,
-    O,
-    P,
-    Q,
-    R,
-    S,
-    T,
-    U,
-    V,
-    W,
-    X,
-    Y,
-    Z,
-    NumPad0 = 0x60,
-    NumPad1,
-    NumPad2,
-    NumPad3,
-    NumPad4,
-    NumPad5,
-    NumPad6,
-    NumPad7,
-    NumPad8,
-    NumPad9,
-    NumMul,
-    NumAdd,
-    NumDecimal,
-    NumSub,
-    NumDiv,
-    F1 = 0x70,
-    F2,
-    F3,
-    F4,
-    F5,
-    F6,
-    F7,
-    F8,
-    F9,
-    F10,
-    F11,
-    F12,
-    F13,
-    F14,
-    F15,
-    F16,
-    F17,
-    F18,
-    F19,
-    F20,
-    F21,
-    F22,
-    F23,
-    F24,
-    NumLock = 0x90,
-    ScrollLock = 0x91,
-    LShift = 0xa0,
-    RShift,
-    LCtrl = 0xa2,
-    RCtrl,
-    LAlt = 0xa4,
-    RAlt,
+macro_rules! virtual_keys {
+    ($(($name:ident = $value:expr, $s:expr)),* $(,)?) => {
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[repr(u32)]
+        pub enum VirtualKey {
+            $($name = $value,)*
+        }
+
+        impl VirtualKey {
+            pub const ALL: &'static [VirtualKey] = &[$(VirtualKey::$name),*];
+
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$name => $s,)*
+                }
+            }
+        }
+
+        impl std::convert::TryFrom<VirtualKeyCode> for VirtualKey {
+            type Error = UnknownVirtualKeyCode;
+
+            fn try_from(src: VirtualKeyCode) -> Result<Self, Self::Error> {
+                match src.0 {
+                    $($value => Ok(Self::$name),)*
+                    _ => Err(UnknownVirtualKeyCode(src)),
+                }
+            }
+        }
+    };
+}
+
+virtual_keys! {
+    (LButton = 0x01, "LButton"),
+    (RButton = 0x02, "RButton"),
+    (Cancel = 0x03, "Cancel"),
+    (MButton = 0x04, "MButton"),
+    (XButton1 = 0x05, "XButton1"),
+    (XButton2 = 0x06, "XButton2"),
+    (BackSpace = 0x08, "BackSpace"),
+    (Tab = 0x09, "Tab"),
+    (Clear = 0x0c, "Clear"),
+    (Enter = 0x0d, "Enter"),
+    (Shift = 0x10, "Shift"),
+    (Ctrl = 0x11, "Ctrl"),
+    (Alt = 0x12, "Alt"),
+    (Pause = 0x13, "Pause"),
+    (CapsLock = 0x14, "CapsLock"),
+    (Kana = 0x15, "Kana"),
+    (ImeOn = 0x16, "ImeOn"),
+    (Junja = 0x17, "Junja"),
+    (Final = 0x18, "Final"),
+    (Kanji = 0x19, "Kanji"),
+    (ImeOff = 0x1a, "ImeOff"),
+    (Esc = 0x1b, "Esc"),
+    (Convert = 0x1c, "Convert"),
+    (NonConvert = 0x1d, "NonConvert"),
+    (Accept = 0x1e, "Accept"),
+    (ModeChange = 0x1f, "ModeChange"),
+    (Space = 0x20, "Space"),
+    (PageUp = 0x21, "PageUp"),
+    (PageDown = 0x22, "PageDown"),
+    (End = 0x23, "End"),
+    (Home = 0x24, "Home"),
+    (Left = 0x25, "Left"),
+    (Up = 0x26, "Up"),
+    (Right = 0x27, "Right"),
+    (Down = 0x28, "Down"),
+    (Select = 0x29, "Select"),
+    (Print = 0x2a, "Print"),
+    (Execute = 0x2b, "Execute"),
+    (PrintScreen = 0x2c, "PrintScreen"),
+    (Insert = 0x2d, "Insert"),
+    (Delete = 0x2e, "Delete"),
+    (Help = 0x2f, "Help"),
+    (_0 = 0x30, "0"),
+    (_1 = 0x31, "1"),
+    (_2 = 0x32, "2"),
+    (_3 = 0x33, "3"),
+    (_4 = 0x34, "4"),
+    (_5 = 0x35, "5"),
+    (_6 = 0x36, "6"),
+    (_7 = 0x37, "7"),
+    (_8 = 0x38, "8"),
+    (_9 = 0x39, "9"),
+    (A = 0x41, "A"),
+    (B = 0x42, "B"),
+    (C = 0x43, "C"),
+    (D = 0x44, "D"),
+    (E = 0x45, "E"),
+    (F = 0x46, "F"),
+    (G = 0x47, "G"),
+    (H = 0x48, "H"),
+    (I = 0x49, "I"),
+    (J = 0x4a, "J"),
+    (K = 0x4b, "K"),
+    (L = 0x4c, "L"),
+    (M = 0x4d, "M"),
+    (N = 0x4e, "N"),
+    (O = 0x4f, "O"),
+    (P = 0x50, "P"),
+    (Q = 0x51, "Q"),
+    (R = 0x52, "R"),
+    (S = 0x53, "S"),
+    (T = 0x54, "T"),
+    (U = 0x55, "U"),
+    (V = 0x56, "V"),
+    (W = 0x57, "W"),
+    (X = 0x58, "X"),
+    (Y = 0x59, "Y"),
+    (Z = 0x5a, "Z"),
+    (LWin = 0x5b, "LWin"),
+    (RWin = 0x5c, "RWin"),
+    (Apps = 0x5d, "Apps"),
+    (Sleep = 0x5f, "Sleep"),
+    (NumPad0 = 0x60, "NumPad0"),
+    (NumPad1 = 0x61, "NumPad1"),
+    (NumPad2 = 0x62, "NumPad2"),
+    (NumPad3 = 0x63, "NumPad3"),
+    (NumPad4 = 0x64, "NumPad4"),
+    (NumPad5 = 0x65, "NumPad5"),
+    (NumPad6 = 0x66, "NumPad6"),
+    (NumPad7 = 0x67, "NumPad7"),
+    (NumPad8 = 0x68, "NumPad8"),
+    (NumPad9 = 0x69, "NumPad9"),
+    (NumMul = 0x6a, "NumMul"),
+    (NumAdd = 0x6b, "NumAdd"),
+    (NumSeparator = 0x6c, "NumSeparator"),
+    (NumSub = 0x6d, "NumSub"),
+    (NumDecimal = 0x6e, "NumDecimal"),
+    (NumDiv = 0x6f, "NumDiv"),
+    (F1 = 0x70, "F1"),
+    (F2 = 0x71, "F2"),
+    (F3 = 0x72, "F3"),
+    (F4 = 0x73, "F4"),
+    (F5 = 0x74, "F5"),
+    (F6 = 0x75, "F6"),
+    (F7 = 0x76, "F7"),
+    (F8 = 0x77, "F8"),
+    (F9 = 0x78, "F9"),
+    (F10 = 0x79, "F10"),
+    (F11 = 0x7a, "F11"),
+    (F12 = 0x7b, "F12"),
+    (F13 = 0x7c, "F13"),
+    (F14 = 0x7d, "F14"),
+    (F15 = 0x7e, "F15"),
+    (F16 = 0x7f, "F16"),
+    (F17 = 0x80, "F17"),
+    (F18 = 0x81, "F18"),
+    (F19 = 0x82, "F19"),
+    (F20 = 0x83, "F20"),
+    (F21 = 0x84, "F21"),
+    (F22 = 0x85, "F22"),
+    (F23 = 0x86, "F23"),
+    (F24 = 0x87, "F24"),
+    (NumLock = 0x90, "NumLock"),
+    (ScrollLock = 0x91, "ScrollLock"),
+    (LShift = 0xa0, "LShift"),
+    (RShift = 0xa1, "RShift"),
+    (LCtrl = 0xa2, "LCtrl"),
+    (RCtrl = 0xa3, "RCtrl"),
+    (LAlt = 0xa4, "LAlt"),
+    (RAlt = 0xa5, "RAlt"),
+    (BrowserBack = 0xa6, "BrowserBack"),
+    (BrowserForward = 0xa7, "BrowserForward"),
+    (BrowserRefresh = 0xa8, "BrowserRefresh"),
+    (BrowserStop = 0xa9, "BrowserStop"),
+    (BrowserSearch = 0xaa, "BrowserSearch"),
+    (BrowserFavorites = 0xab, "BrowserFavorites"),
+    (BrowserHome = 0xac, "BrowserHome"),
+    (VolumeMute = 0xad, "VolumeMute"),
+    (VolumeDown = 0xae, "VolumeDown"),
+    (VolumeUp = 0xaf, "VolumeUp"),
+    (MediaNextTrack = 0xb0, "MediaNextTrack"),
+    (MediaPrevTrack = 0xb1, "MediaPrevTrack"),
+    (MediaStop = 0xb2, "MediaStop"),
+    (MediaPlayPause = 0xb3, "MediaPlayPause"),
+    (LaunchMail = 0xb4, "LaunchMail"),
+    (LaunchMediaSelect = 0xb5, "LaunchMediaSelect"),
+    (LaunchApp1 = 0xb6, "LaunchApp1"),
+    (LaunchApp2 = 0xb7, "LaunchApp2"),
+    (Oem1 = 0xba, "Oem1"),
+    (OemPlus = 0xbb, "OemPlus"),
+    (OemComma = 0xbc, "OemComma"),
+    (OemMinus = 0xbd, "OemMinus"),
+    (OemPeriod = 0xbe, "OemPeriod"),
+    (Oem2 = 0xbf, "Oem2"),
+    (Oem3 = 0xc0, "Oem3"),
+    (Oem4 = 0xdb, "Oem4"),
+    (Oem5 = 0xdc, "Oem5"),
+    (Oem6 = 0xdd, "Oem6"),
+    (Oem7 = 0xde, "Oem7"),
+    (Oem8 = 0xdf, "Oem8"),
+    (Oem102 = 0xe2, "Oem102"),
+    (ProcessKey = 0xe5, "ProcessKey"),
+    (Packet = 0xe7, "Packet"),
+    (Attn = 0xf6, "Attn"),
+    (CrSel = 0xf7, "CrSel"),
+    (ExSel = 0xf8, "ExSel"),
+    (EraseEof = 0xf9, "EraseEof"),
+    (Play = 0xfa, "Play"),
+    (Zoom = 0xfb, "Zoom"),
+    (Pa1 = 0xfd, "Pa1"),
+    (OemClear = 0xfe, "OemClear"),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, thiserror::Error)]
+#[error("unknown virtual key code: {:#04x}", (.0).0)]
+pub struct UnknownVirtualKeyCode(pub VirtualKeyCode);
+
+#[derive(Clone, PartialEq, Eq, Debug, thiserror::Error)]
+#[error("unknown virtual key: {0}")]
+pub struct ParseVirtualKeyError(String);
+
+impl std::fmt::Display for VirtualKey {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Also accepts `"NumpadEnter"`, which parses to `Enter`: Windows reports numpad Enter as
+/// `VK_RETURN` and only `KeyCode::extended` tells the two apart.
+impl std::str::FromStr for VirtualKey {
+    type Err = ParseVirtualKeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("NumpadEnter") {
+            return Ok(Self::Enter);
+        }
+        Self::ALL
+            .iter()
+            .find(|key| key.as_str().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or_else(|| ParseVirtualKeyError(s.into()))
+    }
 }
 
 impl VirtualKeyCode {
     #[inline]
     pub fn from_char(c: char) -> Option<Self> {
-        if let Some(i) = ('0'..'9').position(|d| d == c) {
-            Some(Self(VirtualKey::_0 as u32 + i as u32))
-        } else if let Some(i) = ('A'..'Z').position(|d| d == c) {
-            Some(Self(VirtualKey::A as u32 + i as u32))
-        } else {
-            None
+        match c {
+            '0'..='9' => Some(Self(VirtualKey::_0 as u32 + (c as u32 - '0' as u32))),
+            'A'..='Z' => Some(Self(VirtualKey::A as u32 + (c as u32 - 'A' as u32))),
+            'a'..='z' => Some(Self(VirtualKey::A as u32 + (c as u32 - 'a' as u32))),
+            _ => None,
         }
     }
 
@@ -312,6 +639,15 @@ pub struct VirtualKeyCode(pub u32);
 pub struct KeyCode {
     pub vkey: VirtualKeyCode,
     pub scan_code: u32,
+    pub extended: bool,
+    pub physical_key: PhysicalKey,
+}
+
+impl KeyCode {
+    #[inline]
+    pub fn is_numpad_enter(&self) -> bool {
+        self.vkey == VirtualKey::Enter && self.extended
+    }
 }
 
 impl PartialEq<VirtualKey> for VirtualKeyCode {
@@ -333,6 +669,20 @@ impl PartialEq<VirtualKeyCode> for VirtualKey {
     }
 }
 
+impl PartialEq<PhysicalKey> for KeyCode {
+    #[inline]
+    fn eq(&self, other: &PhysicalKey) -> bool {
+        self.physical_key == *other
+    }
+}
+
+impl PartialEq<KeyCode> for PhysicalKey {
+    #[inline]
+    fn eq(&self, other: &KeyCode) -> bool {
+        other == self
+    }
+}
+
 impl PartialEq<VirtualKey> for KeyCode {
     #[inline]
     fn eq(&self, other: &VirtualKey) -> bool {
@@ -429,20 +779,72 @@ mod tests {
 
     #[test]
     fn virtual_key_code_from_char() {
-        for (i, c) in ('0'..'9').enumerate() {
+        for (i, c) in ('0'..='9').enumerate() {
             let fc = VirtualKeyCode::from_char(c).unwrap();
             assert!(fc == VirtualKeyCode(VirtualKey::_0 as u32 + i as u32));
         }
-        for (i, c) in ('A'..'Z').enumerate() {
+        for (i, c) in ('A'..='Z').enumerate() {
             let fc = VirtualKeyCode::from_char(c).unwrap();
             assert!(fc == VirtualKeyCode(VirtualKey::A as u32 + i as u32));
         }
+        for (i, c) in ('a'..='z').enumerate() {
+            let fc = VirtualKeyCode::from_char(c).unwrap();
+            assert!(fc == VirtualKeyCode(VirtualKey::A as u32 + i as u32));
+        }
+        assert!(VirtualKeyCode::from_char('9').unwrap() == VirtualKey::_9);
+        assert!(VirtualKeyCode::from_char('Z').unwrap() == VirtualKey::Z);
         assert!(VirtualKeyCode::from_char('!').is_none());
     }
 
+    #[test]
+    fn virtual_key_round_trip() {
+        use std::convert::TryFrom;
+        for key in VirtualKey::ALL {
+            let code = VirtualKeyCode::from(*key);
+            assert!(VirtualKey::try_from(code) == Ok(*key));
+            assert!(key.to_string().parse::<VirtualKey>() == Ok(*key));
+        }
+        let mut codes = VirtualKey::ALL
+            .iter()
+            .map(|key| *key as u32)
+            .collect::<Vec<_>>();
+        codes.sort_unstable();
+        codes.dedup();
+        assert!(codes.len() == VirtualKey::ALL.len());
+        assert!(VirtualKey::try_from(VirtualKeyCode(0x07)).is_err());
+    }
+
+    #[test]
+    fn virtual_key_names() {
+        assert!(VirtualKey::_0.to_string() == "0");
+        assert!(VirtualKey::Oem1.to_string() == "Oem1");
+        assert!("pageup".parse::<VirtualKey>() == Ok(VirtualKey::PageUp));
+        assert!("9".parse::<VirtualKey>() == Ok(VirtualKey::_9));
+        assert!("_9".parse::<VirtualKey>().is_err());
+        assert!(VirtualKey::NumDecimal as u32 == 0x6e);
+        assert!(VirtualKey::NumDiv as u32 == 0x6f);
+    }
+
+    #[test]
+    fn numpad_enter() {
+        assert!("NumpadEnter".parse::<VirtualKey>() == Ok(VirtualKey::Enter));
+        assert!("numpadenter".parse::<VirtualKey>() == Ok(VirtualKey::Enter));
+        let key_code = |extended| KeyCode {
+            vkey: VirtualKey::Enter.into(),
+            scan_code: 0x1c,
+            extended,
+            physical_key: PhysicalKey::from_scan_code(0x1c, extended),
+        };
+        assert!(key_code(true).is_numpad_enter());
+        assert!(key_code(true) == PhysicalKey::NumpadEnter);
+        assert!(key_code(true) == VirtualKey::Enter);
+        assert!(!key_code(false).is_numpad_enter());
+        assert!(key_code(false) == PhysicalKey::Enter);
+    }
+
     #[test]
     fn f_keys() {
-        for i in 1u32..24 {
+        for i in 1u32..=24 {
             let f = VirtualKeyCode::f(i).unwrap();
             assert!(f == VirtualKeyCode(VirtualKey::F1 as u32 + i - 1));
         }
@@ -459,6 +861,63 @@ mod tests {
         assert!(VirtualKeyCode(VirtualKey::RAlt as u32) == VirtualKey::Alt);
     }
 
+    #[test]
+    fn modifiers() {
+        let m = Modifier::LShift | Modifier::RCtrl;
+        assert!(m.shift() && m.ctrl() && !m.alt() && !m.win());
+        assert!(m.contains(Modifier::LShift) && !m.contains(Modifier::RShift));
+        let m = m | Modifier::RWin;
+        assert!(m.win());
+        assert!(
+            m.iter().collect::<Vec<_>>() == [Modifier::LShift, Modifier::RCtrl, Modifier::RWin]
+        );
+        assert!(Modifiers::default().is_empty());
+        assert!(
+            Modifiers::new(&[Modifier::LAlt, Modifier::RAlt]) == Modifier::LAlt | Modifier::RAlt
+        );
+        assert!(format!("{:?}", Modifiers::from(Modifier::LAlt)) == "Modifiers([LAlt])");
+    }
+
+    #[test]
+    fn keyboard_state() {
+        let mut raw = [0u8; 256];
+        raw[VirtualKey::Shift as usize] = 0x80;
+        raw[VirtualKey::LShift as usize] = 0x80;
+        raw[VirtualKey::A as usize] = 0x81;
+        raw[VirtualKey::CapsLock as usize] = 0x01;
+        raw[VirtualKey::LButton as usize] = 0x80;
+        raw[VirtualKey::XButton2 as usize] = 0x80;
+        let state = KeyboardState::new(raw);
+        assert!(state.is_down(VirtualKey::Shift) && state.is_down(VirtualKey::LShift));
+        assert!(!state.is_down(VirtualKey::RShift));
+        assert!(state.key_state(VirtualKey::A) == ButtonState::Pressed);
+        assert!(state.key_state(VirtualKey::B) == ButtonState::Released);
+        assert!(state.is_toggled(VirtualKey::A));
+        assert!(!state.is_down(VirtualKey::CapsLock));
+        assert!(
+            state.lock_state()
+                == LockState {
+                    caps_lock: true,
+                    num_lock: false,
+                    scroll_lock: false,
+                }
+        );
+        assert!(
+            state.keys_down().collect::<Vec<_>>()
+                == [
+                    VirtualKey::LButton,
+                    VirtualKey::XButton2,
+                    VirtualKey::Shift,
+                    VirtualKey::A,
+                    VirtualKey::LShift,
+                ]
+        );
+        assert!(
+            state.mouse_buttons().iter().collect::<Vec<_>>()
+                == [MouseButton::Left, MouseButton::Ex1]
+        );
+    }
+
     #[test]
     fn button_state_and_key_state() {
         assert!(ButtonState::Pressed == KeyState::Pressed);
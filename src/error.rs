@@ -1,3 +1,4 @@
+use crate::KeyStroke;
 use windows::core::HRESULT;
 
 #[derive(Debug, thiserror::Error)]
@@ -6,6 +7,8 @@ pub enum Error {
     Api(windows::core::Error),
     #[error("closed")]
     Closed,
+    #[error("hotkey already registered: {}", .0)]
+    HotkeyAlreadyRegistered(KeyStroke),
 }
 
 impl From<HRESULT> for Error {
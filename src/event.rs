@@ -70,11 +70,46 @@ pub struct MouseWheel {
     pub mouse_state: MouseState,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct KeyInput {
     pub state: ButtonState,
     pub key_code: KeyCode,
+    pub key: Key,
+    pub text: Option<String>,
     pub prev_state: ButtonState,
+    pub repeat: bool,
+    pub repeat_count: u16,
+    pub modifiers: Modifiers,
+    pub lock_state: LockState,
+}
+
+impl From<&KeyInput> for input::InputEvent {
+    #[inline]
+    fn from(src: &KeyInput) -> Self {
+        Self::Key {
+            key_code: src.key_code.vkey,
+            state: src.state,
+            modifiers: src.modifiers,
+        }
+    }
+}
+
+impl From<KeyInput> for input::InputEvent {
+    #[inline]
+    fn from(src: KeyInput) -> Self {
+        (&src).into()
+    }
+}
+
+impl From<MouseInput> for input::InputEvent {
+    #[inline]
+    fn from(src: MouseInput) -> Self {
+        Self::MouseButton {
+            button: src.button,
+            state: src.button_state,
+            mouse_state: src.mouse_state,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -1,8 +1,8 @@
-pub use gecl::{Point, Size};
+pub use gecl::{Point, Rect, Size, Vector};
 
 pub const DEFAULT_DPI: i32 = 96;
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct Logical<T>(pub T);
@@ -23,7 +23,7 @@ impl<T> std::ops::DerefMut for Logical<T> {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct Physical<T>(pub T);
@@ -44,7 +44,7 @@ impl<T> std::ops::DerefMut for Physical<T> {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct Screen<T>(pub T);
@@ -65,25 +65,256 @@ impl<T> std::ops::DerefMut for Screen<T> {
     }
 }
 
-#[inline]
-fn to_physical_value<T>(a: T, dpi: T) -> T
-where
-    T: num::traits::NumOps + num::NumCast + Copy,
-{
-    a * dpi / num::cast(DEFAULT_DPI).unwrap()
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dpi(pub u32);
+
+impl Dpi {
+    pub const DEFAULT: Self = Self(DEFAULT_DPI as u32);
+
+    #[inline]
+    pub fn new(value: u32) -> Self {
+        Self(value)
+    }
+
+    #[inline]
+    pub fn value(&self) -> u32 {
+        self.0
+    }
+
+    #[inline]
+    pub fn scale_factor(&self) -> ScaleFactor {
+        ScaleFactor(self.0 as f64 / DEFAULT_DPI as f64)
+    }
+}
+
+impl Default for Dpi {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl From<u32> for Dpi {
+    #[inline]
+    fn from(src: u32) -> Self {
+        Self(src)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScaleFactor(pub f64);
+
+impl ScaleFactor {
+    pub const IDENTITY: Self = Self(1.0);
+
+    #[inline]
+    pub fn new(value: f64) -> Self {
+        Self(value)
+    }
+
+    #[inline]
+    pub fn value(&self) -> f64 {
+        self.0
+    }
+
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        Self(1.0 / self.0)
+    }
+
+    #[inline]
+    pub fn to_dpi(&self) -> Dpi {
+        Dpi((self.0 * DEFAULT_DPI as f64).round() as u32)
+    }
+}
+
+impl Default for ScaleFactor {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<f64> for ScaleFactor {
+    #[inline]
+    fn from(src: f64) -> Self {
+        Self(src)
+    }
 }
 
+impl From<Dpi> for ScaleFactor {
+    #[inline]
+    fn from(src: Dpi) -> Self {
+        src.scale_factor()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Rounding {
+    Floor,
+    Round,
+    Ceil,
+}
+
+impl Rounding {
+    #[inline]
+    fn apply(self, value: f64) -> f64 {
+        match self {
+            Self::Floor => value.floor(),
+            Self::Round => value.round(),
+            Self::Ceil => value.ceil(),
+        }
+    }
+}
+
+impl Default for Rounding {
+    #[inline]
+    fn default() -> Self {
+        Self::Round
+    }
+}
+
+pub trait ScaleValue: num::ToPrimitive + Copy {
+    fn checked_scale(self, factor: ScaleFactor, rounding: Rounding) -> Option<Self>;
+    fn saturating_scale(self, factor: ScaleFactor, rounding: Rounding) -> Self;
+}
+
+macro_rules! impl_scale_value_int {
+    ($($t:ty),*) => {
+        $(
+            impl ScaleValue for $t {
+                #[inline]
+                fn checked_scale(self, factor: ScaleFactor, rounding: Rounding) -> Option<Self> {
+                    num::cast(rounding.apply(self as f64 * factor.0))
+                }
+
+                #[inline]
+                fn saturating_scale(self, factor: ScaleFactor, rounding: Rounding) -> Self {
+                    rounding.apply(self as f64 * factor.0) as Self
+                }
+            }
+        )*
+    };
+}
+
+impl_scale_value_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+macro_rules! impl_scale_value_float {
+    ($($t:ty),*) => {
+        $(
+            impl ScaleValue for $t {
+                #[inline]
+                fn checked_scale(self, factor: ScaleFactor, _rounding: Rounding) -> Option<Self> {
+                    let value = (self as f64 * factor.0) as Self;
+                    value.is_finite().then_some(value)
+                }
+
+                #[inline]
+                fn saturating_scale(self, factor: ScaleFactor, _rounding: Rounding) -> Self {
+                    ((self as f64 * factor.0) as Self).clamp(Self::MIN, Self::MAX)
+                }
+            }
+        )*
+    };
+}
+
+impl_scale_value_float!(f32, f64);
+
 #[inline]
-fn to_logical_value<T>(a: T, dpi: T) -> T
-where
-    T: num::traits::NumOps + num::NumCast + Copy,
-{
-    a * num::cast(DEFAULT_DPI).unwrap() / dpi
+fn dpi_to_scale_factor<T: num::ToPrimitive>(dpi: T) -> ScaleFactor {
+    ScaleFactor(dpi.to_f64().unwrap_or(DEFAULT_DPI as f64) / DEFAULT_DPI as f64)
+}
+
+macro_rules! impl_dpi_conversion {
+    ($shape:ident, |$v:ident, $f:ident| $map:expr) => {
+        impl<T> Logical<$shape<T>>
+        where
+            T: ScaleValue,
+        {
+            #[inline]
+            pub fn to_physical(&self, dpi: T) -> Physical<$shape<T>> {
+                self.to_physical_with(dpi_to_scale_factor(dpi), Rounding::Round)
+            }
+
+            #[inline]
+            pub fn to_physical_with(
+                &self,
+                scale_factor: impl Into<ScaleFactor>,
+                rounding: Rounding,
+            ) -> Physical<$shape<T>> {
+                let scale_factor = scale_factor.into();
+                let $f = |a: T| Some(a.saturating_scale(scale_factor, rounding));
+                let $v = &self.0;
+                let ret: Option<$shape<T>> = (|| $map)();
+                Physical(ret.unwrap())
+            }
+
+            #[inline]
+            pub fn checked_to_physical(
+                &self,
+                scale_factor: impl Into<ScaleFactor>,
+                rounding: Rounding,
+            ) -> Option<Physical<$shape<T>>> {
+                let scale_factor = scale_factor.into();
+                let $f = |a: T| a.checked_scale(scale_factor, rounding);
+                let $v = &self.0;
+                let ret: Option<$shape<T>> = (|| $map)();
+                ret.map(Physical)
+            }
+        }
+
+        impl<T> Physical<$shape<T>>
+        where
+            T: ScaleValue,
+        {
+            #[inline]
+            pub fn to_logical(&self, dpi: T) -> Logical<$shape<T>> {
+                self.to_logical_with(dpi_to_scale_factor(dpi), Rounding::Round)
+            }
+
+            #[inline]
+            pub fn to_logical_with(
+                &self,
+                scale_factor: impl Into<ScaleFactor>,
+                rounding: Rounding,
+            ) -> Logical<$shape<T>> {
+                let scale_factor = scale_factor.into().inverse();
+                let $f = |a: T| Some(a.saturating_scale(scale_factor, rounding));
+                let $v = &self.0;
+                let ret: Option<$shape<T>> = (|| $map)();
+                Logical(ret.unwrap())
+            }
+
+            #[inline]
+            pub fn checked_to_logical(
+                &self,
+                scale_factor: impl Into<ScaleFactor>,
+                rounding: Rounding,
+            ) -> Option<Logical<$shape<T>>> {
+                let scale_factor = scale_factor.into().inverse();
+                let $f = |a: T| a.checked_scale(scale_factor, rounding);
+                let $v = &self.0;
+                let ret: Option<$shape<T>> = (|| $map)();
+                ret.map(Logical)
+            }
+        }
+    };
 }
 
+impl_dpi_conversion!(Point, |v, f| Some(Point::new(f(v.x)?, f(v.y)?)));
+impl_dpi_conversion!(Size, |v, f| Some(Size::new(f(v.width)?, f(v.height)?)));
+impl_dpi_conversion!(Vector, |v, f| Some(Vector::new(f(v.x)?, f(v.y)?)));
+impl_dpi_conversion!(Rect, |v, f| Some(Rect::new(
+    (f(v.origin.x)?, f(v.origin.y)?),
+    (f(v.size.width)?, f(v.size.height)?)
+)));
+
 impl<T> Logical<Point<T>>
 where
-    T: num::traits::NumOps + num::NumCast + Copy,
+    T: num::ToPrimitive + Copy,
 {
     #[inline]
     pub fn cast<U>(&self) -> Option<Logical<Point<U>>>
@@ -92,19 +323,11 @@ where
     {
         self.0.cast().map(|v| Logical(v))
     }
-
-    #[inline]
-    pub fn to_physical(&self, dpi: T) -> Physical<Point<T>> {
-        Physical(Point::new(
-            to_physical_value(self.x, dpi),
-            to_physical_value(self.y, dpi),
-        ))
-    }
 }
 
 impl<T> Logical<Size<T>>
 where
-    T: num::traits::NumOps + num::NumCast + Copy,
+    T: num::ToPrimitive + Copy,
 {
     #[inline]
     pub fn cast<U>(&self) -> Option<Logical<Size<U>>>
@@ -113,19 +336,11 @@ where
     {
         self.0.cast().map(|v| Logical(v))
     }
-
-    #[inline]
-    pub fn to_physical(&self, dpi: T) -> Physical<Size<T>> {
-        Physical(Size::new(
-            to_physical_value(self.width, dpi),
-            to_physical_value(self.height, dpi),
-        ))
-    }
 }
 
 impl<T> Physical<Point<T>>
 where
-    T: num::traits::NumOps + num::NumCast + Copy,
+    T: num::ToPrimitive + Copy,
 {
     #[inline]
     pub fn cast<U>(&self) -> Option<Physical<Point<U>>>
@@ -134,19 +349,11 @@ where
     {
         self.0.cast().map(|v| Physical(v))
     }
-
-    #[inline]
-    pub fn to_logical(&self, dpi: T) -> Logical<Point<T>> {
-        Logical(Point::new(
-            to_logical_value(self.x, dpi),
-            to_logical_value(self.y, dpi),
-        ))
-    }
 }
 
 impl<T> Physical<Size<T>>
 where
-    T: num::traits::NumOps + num::NumCast + Copy,
+    T: num::ToPrimitive + Copy,
 {
     #[inline]
     pub fn cast<U>(&self) -> Option<Physical<Size<U>>>
@@ -155,14 +362,6 @@ where
     {
         self.0.cast().map(|v| Physical(v))
     }
-
-    #[inline]
-    pub fn to_logical(&self, dpi: T) -> Logical<Size<T>> {
-        Logical(Size::new(
-            to_logical_value(self.width, dpi),
-            to_logical_value(self.height, dpi),
-        ))
-    }
 }
 
 impl<T> Screen<Point<T>>
@@ -178,11 +377,491 @@ where
     }
 }
 
+fn partial_min<T: PartialOrd>(a: T, b: T) -> T {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+fn partial_max<T: PartialOrd>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+macro_rules! impl_rect {
+    ($unit:ident) => {
+        impl<T> $unit<Rect<T>>
+        where
+            T: num::Num + num::NumCast + PartialOrd + Copy,
+        {
+            #[inline]
+            pub fn cast<U>(&self) -> Option<$unit<Rect<U>>>
+            where
+                U: num::NumCast,
+            {
+                self.0.cast().map($unit)
+            }
+
+            #[inline]
+            pub fn from_points(a: $unit<Point<T>>, b: $unit<Point<T>>) -> Self {
+                $unit(Rect::from_points(a.0, b.0))
+            }
+
+            #[inline]
+            pub fn left(&self) -> T {
+                self.origin.x
+            }
+
+            #[inline]
+            pub fn top(&self) -> T {
+                self.origin.y
+            }
+
+            #[inline]
+            pub fn right(&self) -> T {
+                self.origin.x + self.size.width
+            }
+
+            #[inline]
+            pub fn bottom(&self) -> T {
+                self.origin.y + self.size.height
+            }
+
+            #[inline]
+            pub fn is_empty(&self) -> bool {
+                !(self.size.width > T::zero() && self.size.height > T::zero())
+            }
+
+            #[inline]
+            pub fn contains(&self, pt: &$unit<Point<T>>) -> bool {
+                pt.x >= self.left()
+                    && pt.x < self.right()
+                    && pt.y >= self.top()
+                    && pt.y < self.bottom()
+            }
+
+            #[inline]
+            pub fn contains_rect(&self, other: &Self) -> bool {
+                other.is_empty()
+                    || (other.left() >= self.left()
+                        && other.top() >= self.top()
+                        && other.right() <= self.right()
+                        && other.bottom() <= self.bottom())
+            }
+
+            #[inline]
+            pub fn intersects(&self, other: &Self) -> bool {
+                self.intersection(other).is_some()
+            }
+
+            pub fn intersection(&self, other: &Self) -> Option<Self> {
+                let left = partial_max(self.left(), other.left());
+                let top = partial_max(self.top(), other.top());
+                let right = partial_min(self.right(), other.right());
+                let bottom = partial_min(self.bottom(), other.bottom());
+                (left < right && top < bottom)
+                    .then(|| $unit(Rect::new((left, top), (right - left, bottom - top))))
+            }
+
+            pub fn union(&self, other: &Self) -> Self {
+                if other.is_empty() {
+                    return *self;
+                }
+                if self.is_empty() {
+                    return *other;
+                }
+                let left = partial_min(self.left(), other.left());
+                let top = partial_min(self.top(), other.top());
+                let right = partial_max(self.right(), other.right());
+                let bottom = partial_max(self.bottom(), other.bottom());
+                $unit(Rect::new((left, top), (right - left, bottom - top)))
+            }
+
+            #[inline]
+            pub fn inflate(&self, dx: T, dy: T) -> Self {
+                let two = T::one() + T::one();
+                $unit(Rect::new(
+                    (self.origin.x - dx, self.origin.y - dy),
+                    (self.size.width + dx * two, self.size.height + dy * two),
+                ))
+            }
+
+            #[inline]
+            pub fn translate(&self, d: impl Into<Vector<T>>) -> Self {
+                $unit(self.0.translate(d))
+            }
+        }
+    };
+}
+
+impl_rect!(Logical);
+impl_rect!(Physical);
+impl_rect!(Screen);
+
+macro_rules! impl_unit_ops {
+    ($unit:ident) => {
+        impl<T: std::hash::Hash> std::hash::Hash for $unit<Point<T>> {
+            #[inline]
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                self.x.hash(state);
+                self.y.hash(state);
+            }
+        }
+
+        impl<T: std::hash::Hash> std::hash::Hash for $unit<Size<T>> {
+            #[inline]
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                self.width.hash(state);
+                self.height.hash(state);
+            }
+        }
+
+        impl<T: std::hash::Hash> std::hash::Hash for $unit<Vector<T>> {
+            #[inline]
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                self.x.hash(state);
+                self.y.hash(state);
+            }
+        }
+
+        impl<T: std::hash::Hash> std::hash::Hash for $unit<Rect<T>> {
+            #[inline]
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                self.origin.x.hash(state);
+                self.origin.y.hash(state);
+                self.size.width.hash(state);
+                self.size.height.hash(state);
+            }
+        }
+
+        impl<T: Default> Default for $unit<Point<T>> {
+            #[inline]
+            fn default() -> Self {
+                $unit(Point::new(T::default(), T::default()))
+            }
+        }
+
+        impl<T: Default> Default for $unit<Size<T>> {
+            #[inline]
+            fn default() -> Self {
+                $unit(Size::new(T::default(), T::default()))
+            }
+        }
+
+        impl<T: Default> Default for $unit<Vector<T>> {
+            #[inline]
+            fn default() -> Self {
+                $unit(Vector::new(T::default(), T::default()))
+            }
+        }
+
+        impl<T: Default> Default for $unit<Rect<T>> {
+            #[inline]
+            fn default() -> Self {
+                $unit(Rect::new(
+                    (T::default(), T::default()),
+                    (T::default(), T::default()),
+                ))
+            }
+        }
+
+        impl<T> std::ops::Add<$unit<Vector<T>>> for $unit<Point<T>>
+        where
+            T: std::ops::Add<T, Output = T>,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: $unit<Vector<T>>) -> Self {
+                $unit(Point::new(self.0.x + rhs.0.x, self.0.y + rhs.0.y))
+            }
+        }
+
+        impl<T> std::ops::Sub<$unit<Vector<T>>> for $unit<Point<T>>
+        where
+            T: std::ops::Sub<T, Output = T>,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: $unit<Vector<T>>) -> Self {
+                $unit(Point::new(self.0.x - rhs.0.x, self.0.y - rhs.0.y))
+            }
+        }
+
+        impl<T> std::ops::Sub<$unit<Point<T>>> for $unit<Point<T>>
+        where
+            T: std::ops::Sub<T, Output = T>,
+        {
+            type Output = $unit<Vector<T>>;
+
+            #[inline]
+            fn sub(self, rhs: $unit<Point<T>>) -> $unit<Vector<T>> {
+                $unit(Vector::new(self.0.x - rhs.0.x, self.0.y - rhs.0.y))
+            }
+        }
+
+        impl<T> std::ops::AddAssign<$unit<Vector<T>>> for $unit<Point<T>>
+        where
+            T: std::ops::AddAssign<T>,
+        {
+            #[inline]
+            fn add_assign(&mut self, rhs: $unit<Vector<T>>) {
+                self.0.x += rhs.0.x;
+                self.0.y += rhs.0.y;
+            }
+        }
+
+        impl<T> std::ops::SubAssign<$unit<Vector<T>>> for $unit<Point<T>>
+        where
+            T: std::ops::SubAssign<T>,
+        {
+            #[inline]
+            fn sub_assign(&mut self, rhs: $unit<Vector<T>>) {
+                self.0.x -= rhs.0.x;
+                self.0.y -= rhs.0.y;
+            }
+        }
+
+        impl<T> std::ops::Add for $unit<Vector<T>>
+        where
+            T: std::ops::Add<T, Output = T>,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                $unit(Vector::new(self.0.x + rhs.0.x, self.0.y + rhs.0.y))
+            }
+        }
+
+        impl<T> std::ops::Sub for $unit<Vector<T>>
+        where
+            T: std::ops::Sub<T, Output = T>,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                $unit(Vector::new(self.0.x - rhs.0.x, self.0.y - rhs.0.y))
+            }
+        }
+
+        impl<T> std::ops::Add for $unit<Size<T>>
+        where
+            T: std::ops::Add<T, Output = T>,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                $unit(Size::new(
+                    self.0.width + rhs.0.width,
+                    self.0.height + rhs.0.height,
+                ))
+            }
+        }
+
+        impl<T> std::ops::Sub for $unit<Size<T>>
+        where
+            T: std::ops::Sub<T, Output = T>,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                $unit(Size::new(
+                    self.0.width - rhs.0.width,
+                    self.0.height - rhs.0.height,
+                ))
+            }
+        }
+
+        impl<T> std::ops::Mul<T> for $unit<Point<T>>
+        where
+            T: std::ops::Mul<T, Output = T> + Copy,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: T) -> Self {
+                $unit(self.0 * rhs)
+            }
+        }
+
+        impl<T> std::ops::Div<T> for $unit<Point<T>>
+        where
+            T: std::ops::Div<T, Output = T> + Copy,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, rhs: T) -> Self {
+                $unit(self.0 / rhs)
+            }
+        }
+
+        impl<T> std::ops::Mul<T> for $unit<Size<T>>
+        where
+            T: std::ops::Mul<T, Output = T> + Copy,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: T) -> Self {
+                $unit(self.0 * rhs)
+            }
+        }
+
+        impl<T> std::ops::Div<T> for $unit<Size<T>>
+        where
+            T: std::ops::Div<T, Output = T> + Copy,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, rhs: T) -> Self {
+                $unit(self.0 / rhs)
+            }
+        }
+
+        impl<T> std::ops::Mul<T> for $unit<Vector<T>>
+        where
+            T: std::ops::Mul<T, Output = T> + Copy,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: T) -> Self {
+                $unit(self.0 * rhs)
+            }
+        }
+
+        impl<T> std::ops::Div<T> for $unit<Vector<T>>
+        where
+            T: std::ops::Div<T, Output = T> + Copy,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, rhs: T) -> Self {
+                $unit(self.0 / rhs)
+            }
+        }
+
+        impl<T> $unit<Point<T>>
+        where
+            T: PartialOrd + Copy,
+        {
+            #[inline]
+            pub fn min(&self, other: &Self) -> Self {
+                $unit(Point::new(
+                    partial_min(self.x, other.x),
+                    partial_min(self.y, other.y),
+                ))
+            }
+
+            #[inline]
+            pub fn max(&self, other: &Self) -> Self {
+                $unit(Point::new(
+                    partial_max(self.x, other.x),
+                    partial_max(self.y, other.y),
+                ))
+            }
+
+            #[inline]
+            pub fn clamp(&self, min: &Self, max: &Self) -> Self {
+                self.max(min).min(max)
+            }
+        }
+
+        impl<T> $unit<Size<T>>
+        where
+            T: PartialOrd + Copy,
+        {
+            #[inline]
+            pub fn min(&self, other: &Self) -> Self {
+                $unit(Size::new(
+                    partial_min(self.width, other.width),
+                    partial_min(self.height, other.height),
+                ))
+            }
+
+            #[inline]
+            pub fn max(&self, other: &Self) -> Self {
+                $unit(Size::new(
+                    partial_max(self.width, other.width),
+                    partial_max(self.height, other.height),
+                ))
+            }
+
+            #[inline]
+            pub fn clamp(&self, min: &Self, max: &Self) -> Self {
+                self.max(min).min(max)
+            }
+        }
+
+        impl<T> $unit<Vector<T>>
+        where
+            T: num::ToPrimitive,
+        {
+            #[inline]
+            pub fn cast<U>(self) -> Option<$unit<Vector<U>>>
+            where
+                U: num::NumCast,
+            {
+                self.0.cast().map($unit)
+            }
+        }
+    };
+}
+
+impl_unit_ops!(Logical);
+impl_unit_ops!(Physical);
+impl_unit_ops!(Screen);
+
+impl<T> Physical<Point<T>>
+where
+    T: std::ops::Add<T, Output = T> + Copy,
+{
+    #[inline]
+    pub fn to_screen(&self, client_origin: Screen<Point<T>>) -> Screen<Point<T>> {
+        Screen(Point::new(
+            self.x + client_origin.x,
+            self.y + client_origin.y,
+        ))
+    }
+}
+
+impl<T> Screen<Point<T>>
+where
+    T: std::ops::Sub<T, Output = T> + Copy,
+{
+    #[inline]
+    pub fn to_client(&self, client_origin: Screen<Point<T>>) -> Physical<Point<T>> {
+        Physical(Point::new(
+            self.x - client_origin.x,
+            self.y - client_origin.y,
+        ))
+    }
+}
+
 pub type LogicalPoint<T> = Logical<Point<T>>;
 pub type LogicalSize<T> = Logical<Size<T>>;
 pub type PhysicalPoint<T> = Physical<Point<T>>;
 pub type PhysicalSize<T> = Physical<Size<T>>;
 pub type ScreenPoint<T> = Screen<Point<T>>;
+pub type LogicalVector<T> = Logical<Vector<T>>;
+pub type PhysicalVector<T> = Physical<Vector<T>>;
+pub type ScreenVector<T> = Screen<Vector<T>>;
+pub type LogicalRect<T> = Logical<Rect<T>>;
+pub type PhysicalRect<T> = Physical<Rect<T>>;
+pub type ScreenRect<T> = Screen<Rect<T>>;
 
 pub trait ToLogical {
     type Output;
@@ -200,7 +879,7 @@ pub trait ToPhysical {
 
 impl<T> ToLogical for Logical<Point<T>>
 where
-    T: num::traits::NumOps + num::NumCast + Copy,
+    T: ScaleValue,
 {
     type Output = Point<T>;
     type Value = T;
@@ -213,7 +892,7 @@ where
 
 impl<T> ToLogical for Physical<Point<T>>
 where
-    T: num::traits::NumOps + num::NumCast + Copy,
+    T: ScaleValue,
 {
     type Output = Point<T>;
     type Value = T;
@@ -226,7 +905,7 @@ where
 
 impl<T> ToPhysical for Logical<Point<T>>
 where
-    T: num::traits::NumOps + num::NumCast + Copy,
+    T: ScaleValue,
 {
     type Output = Point<T>;
     type Value = T;
@@ -239,7 +918,7 @@ where
 
 impl<T> ToPhysical for Physical<Point<T>>
 where
-    T: num::traits::NumOps + num::NumCast + Copy,
+    T: ScaleValue,
 {
     type Output = Point<T>;
     type Value = T;
@@ -252,7 +931,7 @@ where
 
 impl<T> ToLogical for Logical<Size<T>>
 where
-    T: num::traits::NumOps + num::NumCast + Copy,
+    T: ScaleValue,
 {
     type Output = Size<T>;
     type Value = T;
@@ -265,7 +944,7 @@ where
 
 impl<T> ToLogical for Physical<Size<T>>
 where
-    T: num::traits::NumOps + num::NumCast + Copy,
+    T: ScaleValue,
 {
     type Output = Size<T>;
     type Value = T;
@@ -278,7 +957,7 @@ where
 
 impl<T> ToPhysical for Logical<Size<T>>
 where
-    T: num::traits::NumOps + num::NumCast + Copy,
+    T: ScaleValue,
 {
     type Output = Size<T>;
     type Value = T;
@@ -291,7 +970,7 @@ where
 
 impl<T> ToPhysical for Physical<Size<T>>
 where
-    T: num::traits::NumOps + num::NumCast + Copy,
+    T: ScaleValue,
 {
     type Output = Size<T>;
     type Value = T;
@@ -302,6 +981,211 @@ where
     }
 }
 
+impl<T> ToLogical for Logical<Rect<T>>
+where
+    T: ScaleValue,
+{
+    type Output = Rect<T>;
+    type Value = T;
+
+    #[inline]
+    fn to_logical(&self, _dpi: Self::Value) -> Logical<Self::Output> {
+        *self
+    }
+}
+
+impl<T> ToLogical for Physical<Rect<T>>
+where
+    T: ScaleValue,
+{
+    type Output = Rect<T>;
+    type Value = T;
+
+    #[inline]
+    fn to_logical(&self, dpi: Self::Value) -> Logical<Self::Output> {
+        self.to_logical(dpi)
+    }
+}
+
+impl<T> ToPhysical for Logical<Rect<T>>
+where
+    T: ScaleValue,
+{
+    type Output = Rect<T>;
+    type Value = T;
+
+    #[inline]
+    fn to_physical(&self, dpi: Self::Value) -> Physical<Self::Output> {
+        self.to_physical(dpi)
+    }
+}
+
+impl<T> ToPhysical for Physical<Rect<T>>
+where
+    T: ScaleValue,
+{
+    type Output = Rect<T>;
+    type Value = T;
+
+    #[inline]
+    fn to_physical(&self, _dpi: Self::Value) -> Physical<Self::Output> {
+        *self
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Region(Vec<PhysicalRect<i32>>);
+
+impl Region {
+    #[inline]
+    pub fn new() -> Self {
+        Self(vec![])
+    }
+
+    #[inline]
+    pub fn from_rects(rects: impl IntoIterator<Item = PhysicalRect<i32>>) -> Self {
+        let mut region = Self::new();
+        for rc in rects {
+            region.add(rc);
+        }
+        region
+    }
+
+    pub fn ellipse(rect: PhysicalRect<i32>) -> Self {
+        let rx = rect.size.width as f64 / 2.0;
+        let ry = rect.size.height as f64 / 2.0;
+        Self::from_rows(rect, |y| {
+            let dy = (y as f64 + 0.5 - ry) / ry;
+            rx - rx * (1.0 - dy * dy).max(0.0).sqrt()
+        })
+    }
+
+    pub fn rounded_rect(rect: PhysicalRect<i32>, radius: u32) -> Self {
+        let r = (radius as f64)
+            .min(rect.size.width as f64 / 2.0)
+            .min(rect.size.height as f64 / 2.0);
+        let height = rect.size.height as f64;
+        Self::from_rows(rect, |y| {
+            let y = y as f64 + 0.5;
+            let dy = if y < r {
+                r - y
+            } else if y > height - r {
+                y - (height - r)
+            } else {
+                return 0.0;
+            };
+            r - (r * r - dy * dy).max(0.0).sqrt()
+        })
+    }
+
+    fn from_rows(rect: PhysicalRect<i32>, inset: impl Fn(i32) -> f64) -> Self {
+        let mut region = Self::new();
+        let mut run: Option<(i32, i32, i32)> = None;
+        for y in 0..rect.size.height.max(0) {
+            let inset = inset(y).round() as i32;
+            let x0 = inset;
+            let x1 = rect.size.width - inset;
+            match run {
+                Some((_, rx0, rx1)) if rx0 == x0 && rx1 == x1 => {}
+                _ => {
+                    if let Some((start, rx0, rx1)) = run {
+                        region.add(Physical(Rect::new(
+                            (rect.left() + rx0, rect.top() + start),
+                            (rx1 - rx0, y - start),
+                        )));
+                    }
+                    run = Some((y, x0, x1));
+                }
+            }
+        }
+        if let Some((start, rx0, rx1)) = run {
+            region.add(Physical(Rect::new(
+                (rect.left() + rx0, rect.top() + start),
+                (rx1 - rx0, rect.size.height - start),
+            )));
+        }
+        region
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[inline]
+    pub fn rects(&self) -> &[PhysicalRect<i32>] {
+        &self.0
+    }
+
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<'_, PhysicalRect<i32>> {
+        self.0.iter()
+    }
+
+    pub fn add(&mut self, rect: PhysicalRect<i32>) {
+        if rect.is_empty() || self.0.iter().any(|rc| rc.contains_rect(&rect)) {
+            return;
+        }
+        self.0.retain(|rc| !rect.contains_rect(rc));
+        self.0.push(rect);
+    }
+
+    #[inline]
+    pub fn union(&self, other: &Region) -> Region {
+        let mut region = self.clone();
+        for rc in other.iter() {
+            region.add(*rc);
+        }
+        region
+    }
+
+    pub fn intersection(&self, rect: &PhysicalRect<i32>) -> Region {
+        Region::from_rects(self.0.iter().filter_map(|rc| rc.intersection(rect)))
+    }
+
+    #[inline]
+    pub fn contains(&self, pt: &PhysicalPoint<i32>) -> bool {
+        self.0.iter().any(|rc| rc.contains(pt))
+    }
+
+    #[inline]
+    pub fn intersects(&self, rect: &PhysicalRect<i32>) -> bool {
+        self.0.iter().any(|rc| rc.intersects(rect))
+    }
+
+    #[inline]
+    pub fn bounds(&self) -> Option<PhysicalRect<i32>> {
+        let mut iter = self.0.iter();
+        let first = *iter.next()?;
+        Some(iter.fold(first, |bounds, rc| bounds.union(rc)))
+    }
+
+    #[inline]
+    pub fn translate(&self, d: impl Into<Vector<i32>>) -> Region {
+        let d = d.into();
+        Region(self.0.iter().map(|rc| rc.translate(d)).collect())
+    }
+
+    pub fn scale(&self, scale_factor: impl Into<ScaleFactor>) -> Region {
+        let scale_factor = scale_factor.into();
+        Region::from_rects(self.0.iter().map(|rc| {
+            let left = rc.left().saturating_scale(scale_factor, Rounding::Floor);
+            let top = rc.top().saturating_scale(scale_factor, Rounding::Floor);
+            let right = rc.right().saturating_scale(scale_factor, Rounding::Ceil);
+            let bottom = rc.bottom().saturating_scale(scale_factor, Rounding::Ceil);
+            Physical(Rect::new((left, top), (right - left, bottom - top)))
+        }))
+    }
+}
+
+impl From<PhysicalRect<i32>> for Region {
+    #[inline]
+    fn from(src: PhysicalRect<i32>) -> Self {
+        Self::from_rects(Some(src))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -345,4 +1229,351 @@ mod tests {
         assert!(src.x == dest.x * 2);
         assert!(src.y == dest.y * 2);
     }
+
+    #[test]
+    fn rect_to_physical_and_logical() {
+        let src = Logical(Rect::new((10, 20), (30, 40)));
+        let dest = src.to_physical(DEFAULT_DPI * 2);
+        assert!(dest.0 == Rect::new((20, 40), (60, 80)));
+        let back = dest.to_logical(DEFAULT_DPI * 2);
+        assert!(back.0 == src.0);
+    }
+
+    #[test]
+    fn rect_edges() {
+        let rc = Physical(Rect::new((10, 20), (30, 40)));
+        assert!(rc.left() == 10 && rc.top() == 20);
+        assert!(rc.right() == 40 && rc.bottom() == 60);
+        assert!(!rc.is_empty());
+        assert!(Physical(Rect::new((10, 20), (0, 40))).is_empty());
+        assert!(Physical(Rect::new((10, 20), (-1, 40))).is_empty());
+    }
+
+    #[test]
+    fn rect_contains() {
+        let rc = Screen(Rect::new((10, 20), (30, 40)));
+        assert!(rc.contains(&Screen(Point::new(10, 20))));
+        assert!(rc.contains(&Screen(Point::new(39, 59))));
+        assert!(!rc.contains(&Screen(Point::new(40, 20))));
+        assert!(!rc.contains(&Screen(Point::new(10, 60))));
+        assert!(!rc.contains(&Screen(Point::new(9, 30))));
+        assert!(rc.contains_rect(&Screen(Rect::new((15, 25), (10, 10)))));
+        assert!(!rc.contains_rect(&Screen(Rect::new((35, 25), (10, 10)))));
+    }
+
+    #[test]
+    fn rect_intersection() {
+        let a = Physical(Rect::new((0, 0), (100, 100)));
+        let b = Physical(Rect::new((50, 60), (100, 100)));
+        assert!(a.intersection(&b).unwrap().0 == Rect::new((50, 60), (50, 40)));
+        assert!(a.intersects(&b));
+        let c = Physical(Rect::new((100, 0), (10, 10)));
+        assert!(a.intersection(&c).is_none());
+        assert!(!a.intersects(&c));
+    }
+
+    #[test]
+    fn rect_union() {
+        let a = Logical(Rect::new((0, 0), (10, 10)));
+        let b = Logical(Rect::new((20, -5), (10, 10)));
+        assert!(a.union(&b).0 == Rect::new((0, -5), (30, 15)));
+        let empty = Logical(Rect::new((100, 100), (0, 0)));
+        assert!(a.union(&empty).0 == a.0);
+        assert!(empty.union(&a).0 == a.0);
+    }
+
+    #[test]
+    fn rect_inflate_and_translate() {
+        let rc = Physical(Rect::new((10, 20), (30, 40)));
+        assert!(rc.inflate(2, 3).0 == Rect::new((8, 17), (34, 46)));
+        assert!(rc.inflate(-5, -5).0 == Rect::new((15, 25), (20, 30)));
+        assert!(rc.translate((5, -5)).0 == Rect::new((15, 15), (30, 40)));
+    }
+
+    #[test]
+    fn rect_from_points_and_cast() {
+        let rc = Screen::<Rect<i32>>::from_points(
+            Screen(Point::new(30, 40)),
+            Screen(Point::new(10, 20)),
+        );
+        assert!(rc.0 == Rect::new((10, 20), (20, 20)));
+        let rc = rc.cast::<f32>().unwrap();
+        assert!(rc.0 == Rect::new((10.0, 20.0), (20.0, 20.0)));
+    }
+
+    #[test]
+    fn region_add() {
+        let mut region = Region::new();
+        assert!(region.is_empty());
+        region.add(Physical(Rect::new((0, 0), (10, 10))));
+        region.add(Physical(Rect::new((2, 2), (5, 5))));
+        assert!(region.rects().len() == 1);
+        region.add(Physical(Rect::new((0, 0), (0, 10))));
+        assert!(region.rects().len() == 1);
+        region.add(Physical(Rect::new((-5, -5), (30, 30))));
+        assert!(region.rects().len() == 1);
+        assert!(region.rects()[0].0 == Rect::new((-5, -5), (30, 30)));
+    }
+
+    #[test]
+    fn region_contains_and_bounds() {
+        let region = Region::from_rects([
+            Physical(Rect::new((0, 0), (10, 10))),
+            Physical(Rect::new((20, 20), (10, 10))),
+        ]);
+        assert!(region.contains(&Physical(Point::new(5, 5))));
+        assert!(region.contains(&Physical(Point::new(25, 25))));
+        assert!(!region.contains(&Physical(Point::new(15, 15))));
+        assert!(region.bounds().unwrap().0 == Rect::new((0, 0), (30, 30)));
+        assert!(Region::new().bounds().is_none());
+    }
+
+    #[test]
+    fn region_intersection_and_union() {
+        let a = Region::from_rects([
+            Physical(Rect::new((0, 0), (10, 10))),
+            Physical(Rect::new((20, 20), (10, 10))),
+        ]);
+        let clipped = a.intersection(&Physical(Rect::new((5, 5), (20, 20))));
+        assert!(clipped.rects().len() == 2);
+        assert!(clipped.rects()[0].0 == Rect::new((5, 5), (5, 5)));
+        assert!(clipped.rects()[1].0 == Rect::new((20, 20), (5, 5)));
+        assert!(a.intersects(&Physical(Rect::new((8, 8), (4, 4)))));
+        assert!(!a.intersects(&Physical(Rect::new((12, 12), (4, 4)))));
+        let b = Region::from(Physical(Rect::new((40, 40), (5, 5))));
+        assert!(a.union(&b).rects().len() == 3);
+    }
+
+    #[test]
+    fn region_translate() {
+        let region = Region::from(Physical(Rect::new((0, 0), (10, 10)))).translate((3, 4));
+        assert!(region.rects()[0].0 == Rect::new((3, 4), (10, 10)));
+        assert!(region.contains(&Physical(Point::new(12, 13))));
+    }
+
+    #[test]
+    fn eq_and_hash() {
+        use std::collections::HashSet;
+        assert!(Logical(Point::new(1, 2)) == Logical(Point::new(1, 2)));
+        assert!(Physical(Size::new(1, 2)) != Physical(Size::new(2, 1)));
+        let mut set = HashSet::new();
+        set.insert(Physical(Size::new(640u32, 480u32)));
+        set.insert(Physical(Size::new(640u32, 480u32)));
+        set.insert(Physical(Size::new(800u32, 600u32)));
+        assert!(set.len() == 2);
+        let mut set = HashSet::new();
+        set.insert(Screen(Rect::new((0, 0), (10, 10))));
+        assert!(set.contains(&Screen(Rect::new((0, 0), (10, 10)))));
+    }
+
+    #[test]
+    fn default_values() {
+        assert!(LogicalPoint::<i32>::default() == Logical(Point::new(0, 0)));
+        assert!(PhysicalSize::<u32>::default() == Physical(Size::new(0, 0)));
+        assert!(ScreenVector::<i32>::default() == Screen(Vector::new(0, 0)));
+    }
+
+    #[test]
+    fn point_and_vector_ops() {
+        let a = Physical(Point::new(10, 20));
+        let b = Physical(Point::new(4, 5));
+        let d = a - b;
+        assert!(d == Physical(Vector::new(6, 15)));
+        assert!(b + d == a);
+        assert!(a - d == b);
+        let mut c = b;
+        c += d;
+        assert!(c == a);
+        c -= d;
+        assert!(c == b);
+        assert!(d + d == Physical(Vector::new(12, 30)));
+        assert!(d - d == Physical(Vector::new(0, 0)));
+    }
+
+    #[test]
+    fn size_ops() {
+        let a = Logical(Size::new(10u32, 20u32));
+        let b = Logical(Size::new(1u32, 2u32));
+        assert!(a + b == Logical(Size::new(11, 22)));
+        assert!(a - b == Logical(Size::new(9, 18)));
+    }
+
+    #[test]
+    fn scalar_ops() {
+        assert!(Logical(Point::new(1, 2)) * 3 == Logical(Point::new(3, 6)));
+        assert!(Logical(Point::new(3, 6)) / 3 == Logical(Point::new(1, 2)));
+        assert!(Physical(Size::new(2u32, 4u32)) * 2 == Physical(Size::new(4, 8)));
+        assert!(Physical(Size::new(2u32, 4u32)) / 2 == Physical(Size::new(1, 2)));
+        assert!(Screen(Vector::new(2, -4)) * 2 == Screen(Vector::new(4, -8)));
+        assert!(Screen(Vector::new(2, -4)) / 2 == Screen(Vector::new(1, -2)));
+    }
+
+    #[test]
+    fn min_max_clamp() {
+        let a = Physical(Point::new(1, 20));
+        let b = Physical(Point::new(10, 2));
+        assert!(a.min(&b) == Physical(Point::new(1, 2)));
+        assert!(a.max(&b) == Physical(Point::new(10, 20)));
+        let size = Logical(Size::new(1000u32, 10u32));
+        let min = Logical(Size::new(100u32, 100u32));
+        let max = Logical(Size::new(800u32, 600u32));
+        assert!(size.clamp(&min, &max) == Logical(Size::new(800, 100)));
+        let pt = Screen(Point::new(-5, 50));
+        let clamped = pt.clamp(&Screen(Point::new(0, 0)), &Screen(Point::new(10, 10)));
+        assert!(clamped == Screen(Point::new(0, 10)));
+    }
+
+    #[test]
+    fn client_and_screen() {
+        let origin = Screen(Point::new(100, 200));
+        let client = Physical(Point::new(10, 20));
+        let screen = client.to_screen(origin);
+        assert!(screen == Screen(Point::new(110, 220)));
+        assert!(screen.to_client(origin) == client);
+    }
+
+    #[test]
+    fn vector_cast() {
+        assert!(Physical(Vector::new(-1, 2)).cast::<u32>().is_none());
+        assert!(Physical(Vector::new(1, 2)).cast::<u32>() == Some(Physical(Vector::new(1, 2))));
+    }
+
+    #[test]
+    fn dpi_and_scale_factor() {
+        assert!(Dpi::default() == Dpi(96));
+        assert!(Dpi(144).scale_factor() == ScaleFactor(1.5));
+        assert!(ScaleFactor(1.25).to_dpi() == Dpi(120));
+        assert!(ScaleFactor::from(Dpi(192)).inverse() == ScaleFactor(0.5));
+    }
+
+    #[test]
+    fn rounding() {
+        let src = Logical(Size::new(1u32, 3u32));
+        let floor = src.to_physical_with(Dpi(144), Rounding::Floor);
+        let round = src.to_physical_with(Dpi(144), Rounding::Round);
+        let ceil = src.to_physical_with(Dpi(144), Rounding::Ceil);
+        assert!(floor == Physical(Size::new(1, 4)));
+        assert!(round == Physical(Size::new(2, 5)));
+        assert!(ceil == Physical(Size::new(2, 5)));
+        assert!(src.to_physical(144) == round);
+        let src = Physical(Point::new(-5, 5));
+        assert!(src.to_logical_with(Dpi(192), Rounding::Floor) == Logical(Point::new(-3, 2)));
+        assert!(src.to_logical_with(Dpi(192), Rounding::Ceil) == Logical(Point::new(-2, 3)));
+    }
+
+    #[test]
+    fn float_conversion() {
+        let src = Logical(Point::new(10.5f32, -3.25f32));
+        let dst = src.to_physical(144.0);
+        assert!(dst == Physical(Point::new(15.75, -4.875)));
+        assert!(dst.to_logical(144.0) == src);
+        let src = Physical(Size::new(300.0f64, 150.0f64));
+        assert!(
+            src.to_logical_with(ScaleFactor(1.5), Rounding::Floor)
+                == Logical(Size::new(200.0, 100.0))
+        );
+    }
+
+    #[test]
+    fn overflow() {
+        let src = Logical(Size::new(u32::MAX, 1));
+        assert!(src.checked_to_physical(Dpi(192), Rounding::Round).is_none());
+        assert!(src.to_physical(192) == Physical(Size::new(u32::MAX, 2)));
+        let src = Logical(Point::new(i32::MIN, 0));
+        assert!(src.checked_to_physical(Dpi(120), Rounding::Round).is_none());
+        assert!(src.to_physical(120).x == i32::MIN);
+        let src = Physical(Rect::new((0i32, 0i32), (100, 100)));
+        assert!(
+            src.checked_to_logical(Dpi(192), Rounding::Round)
+                == Some(Logical(Rect::new((0, 0), (50, 50))))
+        );
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn round_trip_upscale_is_exact(x in -1_000_000i32..1_000_000, y in -1_000_000i32..1_000_000, dpi in 96u32..=480) {
+            let src = Logical(Point::new(x, y));
+            let dst = src.checked_to_physical(Dpi(dpi), Rounding::Round).unwrap();
+            let back = dst.checked_to_logical(Dpi(dpi), Rounding::Round).unwrap();
+            proptest::prop_assert_eq!(back, src);
+        }
+
+        #[test]
+        fn round_trip_is_bounded(w in 0u32..1_000_000, h in 0u32..1_000_000, dpi in 24u32..=480) {
+            let src = Physical(Size::new(w, h));
+            let logical = src.to_logical_with(Dpi(dpi), Rounding::Round);
+            let back = logical.to_physical_with(Dpi(dpi), Rounding::Round);
+            let bound = Dpi(dpi).scale_factor().value() / 2.0 + 0.5;
+            proptest::prop_assert!((back.width as f64 - w as f64).abs() <= bound);
+            proptest::prop_assert!((back.height as f64 - h as f64).abs() <= bound);
+        }
+
+        #[test]
+        fn rounding_is_ordered(x in -1_000_000i32..1_000_000, dpi in 24u32..=480) {
+            let src = Logical(Point::new(x, 0));
+            let floor = src.to_physical_with(Dpi(dpi), Rounding::Floor).x;
+            let round = src.to_physical_with(Dpi(dpi), Rounding::Round).x;
+            let ceil = src.to_physical_with(Dpi(dpi), Rounding::Ceil).x;
+            proptest::prop_assert!(floor <= round && round <= ceil);
+            proptest::prop_assert!(ceil - floor <= 1);
+        }
+
+        #[test]
+        fn float_round_trip(x in -1.0e6f64..1.0e6, dpi in 24u32..=480) {
+            let src = Logical(Vector::new(x, -x));
+            let back = src.to_physical_with(Dpi(dpi), Rounding::Round).to_logical_with(Dpi(dpi), Rounding::Round);
+            proptest::prop_assert!((back.x - x).abs() <= 1.0e-6);
+            proptest::prop_assert!((back.y + x).abs() <= 1.0e-6);
+        }
+
+        #[test]
+        fn checked_matches_saturating_in_range(w in 0u32..=u32::MAX, dpi in 24u32..=480) {
+            let src = Logical(Size::new(w, 0));
+            let saturating = src.to_physical_with(Dpi(dpi), Rounding::Round);
+            match src.checked_to_physical(Dpi(dpi), Rounding::Round) {
+                Some(checked) => proptest::prop_assert_eq!(checked, saturating),
+                None => proptest::prop_assert_eq!(saturating.width, u32::MAX),
+            }
+        }
+    }
+
+    #[test]
+    fn region_ellipse() {
+        let region = Region::ellipse(Physical(Rect::new((10, 20), (100, 60))));
+        assert!(region.bounds() == Some(Physical(Rect::new((10, 20), (100, 60)))));
+        assert!(region.contains(&Physical(Point::new(60, 50))));
+        assert!(region.contains(&Physical(Point::new(10, 50))));
+        assert!(region.contains(&Physical(Point::new(60, 20))));
+        assert!(!region.contains(&Physical(Point::new(10, 20))));
+        assert!(!region.contains(&Physical(Point::new(109, 79))));
+        assert!(region.rects().len() < 60);
+        assert!(Region::ellipse(Physical(Rect::new((0, 0), (0, 0)))).is_empty());
+    }
+
+    #[test]
+    fn region_rounded_rect() {
+        let region = Region::rounded_rect(Physical(Rect::new((0, 0), (100, 50))), 10);
+        assert!(region.bounds() == Some(Physical(Rect::new((0, 0), (100, 50)))));
+        assert!(!region.contains(&Physical(Point::new(0, 0))));
+        assert!(!region.contains(&Physical(Point::new(99, 49))));
+        assert!(region.contains(&Physical(Point::new(10, 0))));
+        assert!(region.contains(&Physical(Point::new(0, 10))));
+        assert!(region.contains(&Physical(Point::new(0, 39))));
+        let square = Region::rounded_rect(Physical(Rect::new((0, 0), (100, 50))), 0);
+        assert!(square.rects() == [Physical(Rect::new((0, 0), (100, 50)))]);
+    }
+
+    #[test]
+    fn region_scale() {
+        let region = Region::from_rects([
+            Physical(Rect::new((0, 0), (10, 10))),
+            Physical(Rect::new((15, 5), (5, 5))),
+        ]);
+        let scaled = region.scale(Dpi(144));
+        assert!(scaled.rects()[0] == Physical(Rect::new((0, 0), (15, 15))));
+        assert!(scaled.rects()[1] == Physical(Rect::new((22, 7), (8, 8))));
+        let back = scaled.scale(Dpi(144).scale_factor().inverse());
+        assert!(back.contains(&Physical(Point::new(9, 9))));
+        assert!(back.contains(&Physical(Point::new(19, 9))));
+    }
 }
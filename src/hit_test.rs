@@ -0,0 +1,297 @@
+use super::*;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HitArea {
+    Nowhere,
+    Client,
+    Caption,
+    MinimizeButton,
+    MaximizeButton,
+    CloseButton,
+    Left,
+    Top,
+    Right,
+    Bottom,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ResizeEdge {
+    Left,
+    Top,
+    Right,
+    Bottom,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl From<ResizeEdge> for HitArea {
+    #[inline]
+    fn from(src: ResizeEdge) -> Self {
+        match src {
+            ResizeEdge::Left => Self::Left,
+            ResizeEdge::Top => Self::Top,
+            ResizeEdge::Right => Self::Right,
+            ResizeEdge::Bottom => Self::Bottom,
+            ResizeEdge::TopLeft => Self::TopLeft,
+            ResizeEdge::TopRight => Self::TopRight,
+            ResizeEdge::BottomLeft => Self::BottomLeft,
+            ResizeEdge::BottomRight => Self::BottomRight,
+        }
+    }
+}
+
+pub trait HitTest {
+    fn hit_test(
+        &self,
+        position: PhysicalPoint<i32>,
+        size: PhysicalSize<u32>,
+        maximized: bool,
+    ) -> HitArea;
+}
+
+impl<F> HitTest for F
+where
+    F: Fn(PhysicalPoint<i32>) -> HitArea,
+{
+    #[inline]
+    fn hit_test(
+        &self,
+        position: PhysicalPoint<i32>,
+        _size: PhysicalSize<u32>,
+        _maximized: bool,
+    ) -> HitArea {
+        self(position)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct HitTestRegions {
+    resize_border: u32,
+    caption_height: u32,
+    areas: Vec<(PhysicalRect<i32>, HitArea)>,
+}
+
+impl HitTestRegions {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            resize_border: 0,
+            caption_height: 0,
+            areas: vec![],
+        }
+    }
+
+    #[inline]
+    pub fn resize_border(mut self, thickness: u32) -> Self {
+        self.resize_border = thickness;
+        self
+    }
+
+    #[inline]
+    pub fn caption(mut self, height: u32) -> Self {
+        self.caption_height = height;
+        self
+    }
+
+    #[inline]
+    pub fn area(mut self, rect: PhysicalRect<i32>, area: HitArea) -> Self {
+        self.areas.push((rect, area));
+        self
+    }
+
+    fn resize_edge(
+        &self,
+        position: PhysicalPoint<i32>,
+        size: PhysicalSize<u32>,
+    ) -> Option<HitArea> {
+        let border = self.resize_border as i64;
+        if border == 0 {
+            return None;
+        }
+        let x = position.x as i64;
+        let y = position.y as i64;
+        let left = x < border;
+        let top = y < border;
+        let right = x >= size.width as i64 - border;
+        let bottom = y >= size.height as i64 - border;
+        match (left, top, right, bottom) {
+            (true, true, _, _) => Some(HitArea::TopLeft),
+            (_, true, true, _) => Some(HitArea::TopRight),
+            (true, _, _, true) => Some(HitArea::BottomLeft),
+            (_, _, true, true) => Some(HitArea::BottomRight),
+            (true, _, _, _) => Some(HitArea::Left),
+            (_, true, _, _) => Some(HitArea::Top),
+            (_, _, true, _) => Some(HitArea::Right),
+            (_, _, _, true) => Some(HitArea::Bottom),
+            _ => None,
+        }
+    }
+}
+
+impl Default for HitTestRegions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HitTest for HitTestRegions {
+    fn hit_test(
+        &self,
+        position: PhysicalPoint<i32>,
+        size: PhysicalSize<u32>,
+        maximized: bool,
+    ) -> HitArea {
+        let inside = position.x >= 0
+            && position.y >= 0
+            && (position.x as i64) < size.width as i64
+            && (position.y as i64) < size.height as i64;
+        if !inside {
+            return HitArea::Nowhere;
+        }
+        if !maximized {
+            if let Some(edge) = self.resize_edge(position, size) {
+                return edge;
+            }
+        }
+        if let Some((_, area)) = self
+            .areas
+            .iter()
+            .rev()
+            .find(|(rc, _)| rc.contains(&position))
+        {
+            return *area;
+        }
+        if (position.y as i64) < self.caption_height as i64 {
+            return HitArea::Caption;
+        }
+        HitArea::Client
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window_size() -> PhysicalSize<u32> {
+        Physical(Size::new(640, 480))
+    }
+
+    fn regions() -> HitTestRegions {
+        HitTestRegions::new()
+            .resize_border(8)
+            .caption(32)
+            .area(
+                Physical(Rect::new((640 - 48 * 3, 0), (48, 32))),
+                HitArea::MinimizeButton,
+            )
+            .area(
+                Physical(Rect::new((640 - 48 * 2, 0), (48, 32))),
+                HitArea::MaximizeButton,
+            )
+            .area(
+                Physical(Rect::new((640 - 48, 0), (48, 32))),
+                HitArea::CloseButton,
+            )
+    }
+
+    #[test]
+    fn closure() {
+        let f = |pt: PhysicalPoint<i32>| {
+            if pt.y < 10 {
+                HitArea::Caption
+            } else {
+                HitArea::Client
+            }
+        };
+        assert!(f.hit_test(Physical(Point::new(5, 5)), window_size(), false) == HitArea::Caption);
+        assert!(f.hit_test(Physical(Point::new(5, 50)), window_size(), false) == HitArea::Client);
+    }
+
+    #[test]
+    fn empty_regions() {
+        let regions = HitTestRegions::new();
+        let size = window_size();
+        assert!(regions.hit_test(Physical(Point::new(0, 0)), size, false) == HitArea::Client);
+        assert!(regions.hit_test(Physical(Point::new(639, 479)), size, false) == HitArea::Client);
+    }
+
+    #[test]
+    fn outside() {
+        let regions = regions();
+        let size = window_size();
+        assert!(regions.hit_test(Physical(Point::new(-1, 10)), size, false) == HitArea::Nowhere);
+        assert!(regions.hit_test(Physical(Point::new(10, -1)), size, false) == HitArea::Nowhere);
+        assert!(regions.hit_test(Physical(Point::new(640, 10)), size, false) == HitArea::Nowhere);
+        assert!(regions.hit_test(Physical(Point::new(10, 480)), size, false) == HitArea::Nowhere);
+    }
+
+    #[test]
+    fn resize_border() {
+        let regions = regions();
+        let size = window_size();
+        let cases = [
+            ((0, 0), HitArea::TopLeft),
+            ((7, 7), HitArea::TopLeft),
+            ((639, 0), HitArea::TopRight),
+            ((0, 479), HitArea::BottomLeft),
+            ((639, 479), HitArea::BottomRight),
+            ((0, 240), HitArea::Left),
+            ((7, 240), HitArea::Left),
+            ((320, 0), HitArea::Top),
+            ((632, 240), HitArea::Right),
+            ((320, 472), HitArea::Bottom),
+            ((8, 240), HitArea::Client),
+            ((320, 471), HitArea::Client),
+        ];
+        for ((x, y), area) in cases {
+            assert!(regions.hit_test(Physical(Point::new(x, y)), size, false) == area);
+        }
+    }
+
+    #[test]
+    fn maximized_has_no_resize_border() {
+        let regions = regions();
+        let size = window_size();
+        assert!(regions.hit_test(Physical(Point::new(0, 0)), size, true) == HitArea::Caption);
+        assert!(regions.hit_test(Physical(Point::new(0, 240)), size, true) == HitArea::Client);
+        assert!(regions.hit_test(Physical(Point::new(639, 0)), size, true) == HitArea::CloseButton);
+    }
+
+    #[test]
+    fn caption_and_buttons() {
+        let regions = regions();
+        let size = window_size();
+        assert!(regions.hit_test(Physical(Point::new(100, 20)), size, false) == HitArea::Caption);
+        assert!(regions.hit_test(Physical(Point::new(100, 32)), size, false) == HitArea::Client);
+        assert!(
+            regions.hit_test(Physical(Point::new(640 - 48 * 3, 20)), size, false)
+                == HitArea::MinimizeButton
+        );
+        assert!(
+            regions.hit_test(Physical(Point::new(640 - 48 * 2 + 10, 20)), size, false)
+                == HitArea::MaximizeButton
+        );
+        assert!(
+            regions.hit_test(Physical(Point::new(620, 20)), size, false) == HitArea::CloseButton
+        );
+    }
+
+    #[test]
+    fn later_area_wins() {
+        let regions = HitTestRegions::new()
+            .area(Physical(Rect::new((0, 0), (100, 100))), HitArea::Caption)
+            .area(Physical(Rect::new((10, 10), (10, 10))), HitArea::Client);
+        let size = window_size();
+        assert!(regions.hit_test(Physical(Point::new(15, 15)), size, false) == HitArea::Client);
+        assert!(regions.hit_test(Physical(Point::new(50, 50)), size, false) == HitArea::Caption);
+        assert!(regions.hit_test(Physical(Point::new(150, 50)), size, false) == HitArea::Client);
+    }
+}
@@ -0,0 +1,191 @@
+use super::*;
+use once_cell::sync::OnceCell;
+use windows::Win32::{
+    Foundation::*,
+    System::LibraryLoader::GetModuleHandleW,
+    UI::{Input::KeyboardAndMouse::*, WindowsAndMessaging::*},
+};
+
+const MAX_HOTKEY_ID: i32 = 0xbfff;
+
+pub struct HotkeyHandle {
+    id: i32,
+    stroke: KeyStroke,
+    rx: event::Receiver<KeyStroke>,
+}
+
+impl HotkeyHandle {
+    #[inline]
+    pub fn stroke(&self) -> KeyStroke {
+        self.stroke
+    }
+
+    #[inline]
+    pub async fn recv(&mut self) -> Result<KeyStroke, Error> {
+        self.rx.recv().await
+    }
+
+    #[inline]
+    pub fn try_recv(&mut self) -> Result<Option<KeyStroke>, Error> {
+        self.rx.try_recv()
+    }
+}
+
+impl Drop for HotkeyHandle {
+    fn drop(&mut self) {
+        let id = self.id;
+        UiThread::post_with_context(move |ctx| unregister(ctx, id));
+    }
+}
+
+fn modifiers(stroke: &KeyStroke) -> HOT_KEY_MODIFIERS {
+    let mut modifiers = MOD_NOREPEAT;
+    if stroke.ctrl {
+        modifiers |= MOD_CONTROL;
+    }
+    if stroke.shift {
+        modifiers |= MOD_SHIFT;
+    }
+    if stroke.alt {
+        modifiers |= MOD_ALT;
+    }
+    if stroke.win {
+        modifiers |= MOD_WIN;
+    }
+    modifiers
+}
+
+fn find_id(mut id: i32, used: impl Fn(i32) -> bool) -> (i32, i32) {
+    loop {
+        let next = if id >= MAX_HOTKEY_ID { 1 } else { id + 1 };
+        if !used(id) {
+            return (id, next);
+        }
+        id = next;
+    }
+}
+
+fn next_id(ctx: &Context) -> i32 {
+    let hotkeys = ctx.hotkeys.borrow();
+    let (id, next) = find_id(ctx.next_hotkey_id.get(), |id| hotkeys.contains_key(&id));
+    ctx.next_hotkey_id.set(next);
+    id
+}
+
+fn window_class() -> &'static Vec<u16> {
+    static CLASS_NAME: OnceCell<Vec<u16>> = OnceCell::new();
+    CLASS_NAME.get_or_init(|| unsafe {
+        let class_name = "awita_hotkey_class"
+            .encode_utf16()
+            .chain(Some(0))
+            .collect::<Vec<_>>();
+        let wc = WNDCLASSEXW {
+            cbSize: std::mem::size_of::<WNDCLASSEXW>() as _,
+            lpfnWndProc: Some(window_proc),
+            hInstance: GetModuleHandleW(None),
+            lpszClassName: PWSTR(class_name.as_ptr() as _),
+            ..Default::default()
+        };
+        if RegisterClassExW(&wc) == 0 {
+            panic!("RegisterClassEx failed");
+        }
+        class_name
+    })
+}
+
+fn hotkey_window(ctx: &Context) -> Result<HWND, Error> {
+    if let Some(hwnd) = ctx.hotkey_window.get() {
+        return Ok(hwnd);
+    }
+    let hwnd = unsafe {
+        CreateWindowExW(
+            Default::default(),
+            PWSTR(window_class().as_ptr() as _),
+            None,
+            Default::default(),
+            0,
+            0,
+            0,
+            0,
+            HWND_MESSAGE,
+            None,
+            GetModuleHandleW(None),
+            std::ptr::null_mut(),
+        )
+    };
+    if hwnd == HWND::default() {
+        return Err(windows::core::Error::from_win32().into());
+    }
+    ctx.hotkey_window.set(Some(hwnd));
+    Ok(hwnd)
+}
+
+unsafe extern "system" fn window_proc(
+    hwnd: HWND,
+    msg: u32,
+    wparam: WPARAM,
+    lparam: LPARAM,
+) -> LRESULT {
+    if msg == WM_HOTKEY {
+        let ctx = CONTEXT.with(|ctx| ctx.borrow().as_ref().unwrap().clone());
+        wm_hotkey(&ctx, wparam.0 as _);
+        return LRESULT(0);
+    }
+    DefWindowProcW(hwnd, msg, wparam, lparam)
+}
+
+pub(crate) fn register(ctx: &Context, stroke: KeyStroke) -> Result<HotkeyHandle, Error> {
+    let hwnd = hotkey_window(ctx)?;
+    let id = next_id(ctx);
+    unsafe {
+        if !RegisterHotKey(hwnd, id, modifiers(&stroke), stroke.key as u32).as_bool() {
+            return Err(match GetLastError() {
+                ERROR_HOTKEY_ALREADY_REGISTERED => Error::HotkeyAlreadyRegistered(stroke),
+                _ => windows::core::Error::from_win32().into(),
+            });
+        }
+    }
+    let channel = event::Channel::new(8);
+    let rx = event::Receiver(Some(channel.rx.activate_cloned()));
+    ctx.hotkeys.borrow_mut().insert(id, (stroke, channel));
+    Ok(HotkeyHandle { id, stroke, rx })
+}
+
+fn unregister(ctx: &Context, id: i32) {
+    if ctx.hotkeys.borrow_mut().remove(&id).is_some() {
+        if let Some(hwnd) = ctx.hotkey_window.get() {
+            unsafe {
+                UnregisterHotKey(hwnd, id);
+            }
+        }
+    }
+}
+
+fn wm_hotkey(ctx: &Context, id: i32) {
+    if let Some((stroke, channel)) = ctx.hotkeys.borrow().get(&id) {
+        channel.send(*stroke);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn modifiers_always_norepeat() {
+        let stroke = KeyStroke::new(VirtualKey::F1);
+        assert!(modifiers(&stroke) == MOD_NOREPEAT);
+        let stroke = KeyStroke::new(VirtualKey::A).ctrl().alt();
+        assert!(modifiers(&stroke) == MOD_NOREPEAT | MOD_CONTROL | MOD_ALT);
+        let stroke = KeyStroke::new(VirtualKey::A).ctrl().shift().alt().win();
+        assert!(modifiers(&stroke) == MOD_NOREPEAT | MOD_CONTROL | MOD_SHIFT | MOD_ALT | MOD_WIN);
+    }
+
+    #[test]
+    fn id_wraps_around() {
+        assert!(find_id(1, |_| false) == (1, 2));
+        assert!(find_id(MAX_HOTKEY_ID, |_| false) == (MAX_HOTKEY_ID, 1));
+        let used = [MAX_HOTKEY_ID - 1, MAX_HOTKEY_ID, 1];
+        assert!(find_id(MAX_HOTKEY_ID - 1, |id| used.contains(&id)) == (2, 3));
+    }
+}
@@ -0,0 +1,401 @@
+use super::*;
+use std::collections::HashSet;
+
+#[derive(Clone, Copy, Debug)]
+pub enum InputEvent {
+    Key {
+        key_code: VirtualKeyCode,
+        state: ButtonState,
+        modifiers: Modifiers,
+    },
+    MouseButton {
+        button: MouseButton,
+        state: ButtonState,
+        mouse_state: MouseState,
+    },
+    CursorMoved(MouseState),
+    CursorLeft,
+    Inactivated,
+}
+
+impl From<MouseState> for InputEvent {
+    #[inline]
+    fn from(src: MouseState) -> Self {
+        Self::CursorMoved(src)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct InputState {
+    keys: HashSet<VirtualKeyCode>,
+    pressed_keys: HashSet<VirtualKeyCode>,
+    released_keys: HashSet<VirtualKeyCode>,
+    buttons: MouseButtons,
+    pressed_buttons: MouseButtons,
+    released_buttons: MouseButtons,
+    cursor_position: Option<PhysicalPoint<i32>>,
+    modifiers: Modifiers,
+}
+
+impl InputState {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            keys: HashSet::new(),
+            pressed_keys: HashSet::new(),
+            released_keys: HashSet::new(),
+            buttons: MouseButtons::from(0),
+            pressed_buttons: MouseButtons::from(0),
+            released_buttons: MouseButtons::from(0),
+            cursor_position: None,
+            modifiers: Modifiers::default(),
+        }
+    }
+
+    pub fn process(&mut self, event: impl Into<InputEvent>) {
+        match event.into() {
+            InputEvent::Key {
+                key_code,
+                state,
+                modifiers,
+            } => {
+                self.modifiers = modifiers;
+                match state {
+                    ButtonState::Pressed => {
+                        if self.keys.insert(key_code) {
+                            self.pressed_keys.insert(key_code);
+                        }
+                    }
+                    ButtonState::Released => {
+                        if self.keys.remove(&key_code) {
+                            self.released_keys.insert(key_code);
+                        }
+                    }
+                }
+            }
+            InputEvent::MouseButton {
+                button,
+                state,
+                mouse_state,
+            } => {
+                self.modifiers = mouse_state.modifiers;
+                self.cursor_position = Some(mouse_state.position);
+                match state {
+                    ButtonState::Pressed if !self.buttons.contains(button) => {
+                        self.buttons = self.buttons | button;
+                        self.pressed_buttons = self.pressed_buttons | button;
+                    }
+                    ButtonState::Released if self.buttons.contains(button) => {
+                        self.buttons = without(self.buttons, button);
+                        self.released_buttons = self.released_buttons | button;
+                    }
+                    _ => {}
+                }
+            }
+            InputEvent::CursorMoved(mouse_state) => {
+                self.modifiers = mouse_state.modifiers;
+                self.cursor_position = Some(mouse_state.position);
+                self.set_buttons(mouse_state.buttons);
+            }
+            InputEvent::CursorLeft => {
+                self.cursor_position = None;
+            }
+            InputEvent::Inactivated => {
+                self.released_keys.extend(self.keys.drain());
+                self.set_buttons(MouseButtons::from(0));
+                self.modifiers = Modifiers::default();
+            }
+        }
+    }
+
+    #[inline]
+    pub fn end_frame(&mut self) {
+        self.pressed_keys.clear();
+        self.released_keys.clear();
+        self.pressed_buttons = MouseButtons::from(0);
+        self.released_buttons = MouseButtons::from(0);
+    }
+
+    #[inline]
+    pub fn is_key_down(&self, key: VirtualKey) -> bool {
+        self.keys.iter().any(|k| *k == key)
+    }
+
+    #[inline]
+    pub fn is_key_pressed(&self, key: VirtualKey) -> bool {
+        self.pressed_keys.iter().any(|k| *k == key)
+    }
+
+    #[inline]
+    pub fn is_key_released(&self, key: VirtualKey) -> bool {
+        self.released_keys.iter().any(|k| *k == key)
+    }
+
+    #[inline]
+    pub fn is_button_down(&self, button: MouseButton) -> bool {
+        self.buttons.contains(button)
+    }
+
+    #[inline]
+    pub fn is_button_pressed(&self, button: MouseButton) -> bool {
+        self.pressed_buttons.contains(button)
+    }
+
+    #[inline]
+    pub fn is_button_released(&self, button: MouseButton) -> bool {
+        self.released_buttons.contains(button)
+    }
+
+    #[inline]
+    pub fn keys_down(&self) -> impl Iterator<Item = VirtualKeyCode> + '_ {
+        self.keys.iter().copied()
+    }
+
+    #[inline]
+    pub fn buttons(&self) -> MouseButtons {
+        self.buttons
+    }
+
+    #[inline]
+    pub fn cursor_position(&self) -> Option<PhysicalPoint<i32>> {
+        self.cursor_position
+    }
+
+    #[inline]
+    pub fn modifiers(&self) -> Modifiers {
+        self.modifiers
+    }
+
+    fn set_buttons(&mut self, buttons: MouseButtons) {
+        for button in buttons.iter() {
+            if !self.buttons.contains(button) {
+                self.pressed_buttons = self.pressed_buttons | button;
+            }
+        }
+        for button in self.buttons.iter() {
+            if !buttons.contains(button) {
+                self.released_buttons = self.released_buttons | button;
+            }
+        }
+        self.buttons = buttons;
+    }
+}
+
+impl Default for InputState {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn without(buttons: MouseButtons, button: MouseButton) -> MouseButtons {
+    MouseButtons::new(&buttons.iter().filter(|b| *b != button).collect::<Vec<_>>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn key(key: VirtualKey, state: ButtonState) -> InputEvent {
+        InputEvent::Key {
+            key_code: key.into(),
+            state,
+            modifiers: Modifiers::default(),
+        }
+    }
+
+    fn mouse_state(x: i32, y: i32, buttons: &[MouseButton]) -> MouseState {
+        MouseState {
+            position: Physical(Point::new(x, y)),
+            buttons: MouseButtons::new(buttons),
+            modifiers: Modifiers::default(),
+            lock_state: LockState::default(),
+        }
+    }
+
+    fn button(button: MouseButton, state: ButtonState) -> InputEvent {
+        InputEvent::MouseButton {
+            button,
+            state,
+            mouse_state: mouse_state(0, 0, &[]),
+        }
+    }
+
+    #[test]
+    fn key_edges() {
+        let mut input = InputState::new();
+        input.process(key(VirtualKey::W, ButtonState::Pressed));
+        assert!(input.is_key_down(VirtualKey::W));
+        assert!(input.is_key_pressed(VirtualKey::W));
+        assert!(!input.is_key_released(VirtualKey::W));
+        input.end_frame();
+        input.process(key(VirtualKey::W, ButtonState::Pressed));
+        assert!(input.is_key_down(VirtualKey::W));
+        assert!(!input.is_key_pressed(VirtualKey::W));
+        input.end_frame();
+        input.process(key(VirtualKey::W, ButtonState::Released));
+        assert!(!input.is_key_down(VirtualKey::W));
+        assert!(input.is_key_released(VirtualKey::W));
+        input.end_frame();
+        assert!(!input.is_key_released(VirtualKey::W));
+    }
+
+    #[test]
+    fn tap_within_one_frame() {
+        let mut input = InputState::new();
+        input.process(key(VirtualKey::Space, ButtonState::Pressed));
+        input.process(key(VirtualKey::Space, ButtonState::Released));
+        assert!(!input.is_key_down(VirtualKey::Space));
+        assert!(input.is_key_pressed(VirtualKey::Space));
+        assert!(input.is_key_released(VirtualKey::Space));
+    }
+
+    #[test]
+    fn generic_modifier_keys() {
+        let mut input = InputState::new();
+        input.process(key(VirtualKey::RShift, ButtonState::Pressed));
+        assert!(input.is_key_down(VirtualKey::Shift));
+        assert!(input.is_key_down(VirtualKey::RShift));
+        assert!(!input.is_key_down(VirtualKey::LShift));
+        assert!(input.is_key_pressed(VirtualKey::Shift));
+    }
+
+    #[test]
+    fn modifiers_follow_latest_event() {
+        let mut input = InputState::new();
+        let modifiers = Modifier::LCtrl | Modifier::LShift;
+        input.process(InputEvent::Key {
+            key_code: VirtualKey::S.into(),
+            state: ButtonState::Pressed,
+            modifiers,
+        });
+        assert!(input.modifiers() == modifiers);
+        input.process(mouse_state(1, 2, &[]));
+        assert!(input.modifiers().is_empty());
+    }
+
+    #[test]
+    fn mouse_buttons() {
+        let mut input = InputState::new();
+        input.process(button(MouseButton::Left, ButtonState::Pressed));
+        input.process(button(MouseButton::Left, ButtonState::Pressed));
+        assert!(input.is_button_down(MouseButton::Left));
+        assert!(input.is_button_pressed(MouseButton::Left));
+        input.end_frame();
+        input.process(button(MouseButton::Right, ButtonState::Released));
+        assert!(!input.is_button_released(MouseButton::Right));
+        input.process(button(MouseButton::Left, ButtonState::Released));
+        assert!(!input.is_button_down(MouseButton::Left));
+        assert!(input.is_button_released(MouseButton::Left));
+    }
+
+    #[test]
+    fn cursor_moves_sync_buttons() {
+        let mut input = InputState::new();
+        assert!(input.cursor_position().is_none());
+        input.process(mouse_state(10, 20, &[MouseButton::Middle]));
+        assert!(input.cursor_position() == Some(Physical(Point::new(10, 20))));
+        assert!(input.is_button_down(MouseButton::Middle));
+        assert!(input.is_button_pressed(MouseButton::Middle));
+        input.end_frame();
+        input.process(mouse_state(11, 20, &[]));
+        assert!(!input.is_button_down(MouseButton::Middle));
+        assert!(input.is_button_released(MouseButton::Middle));
+        input.process(InputEvent::CursorLeft);
+        assert!(input.cursor_position().is_none());
+    }
+
+    #[test]
+    fn inactivated_releases_everything() {
+        let mut input = InputState::new();
+        input.process(key(VirtualKey::LAlt, ButtonState::Pressed));
+        input.process(key(VirtualKey::Tab, ButtonState::Pressed));
+        input.process(button(MouseButton::Left, ButtonState::Pressed));
+        input.end_frame();
+        input.process(InputEvent::Inactivated);
+        assert!(input.keys_down().next().is_none());
+        assert!(input.buttons().is_empty());
+        assert!(input.modifiers().is_empty());
+        assert!(input.is_key_released(VirtualKey::Alt));
+        assert!(input.is_key_released(VirtualKey::Tab));
+        assert!(input.is_button_released(MouseButton::Left));
+        input.end_frame();
+        assert!(!input.is_key_released(VirtualKey::Tab));
+    }
+
+    const KEYS: [VirtualKey; 4] = [
+        VirtualKey::W,
+        VirtualKey::A,
+        VirtualKey::LShift,
+        VirtualKey::Space,
+    ];
+    const BUTTONS: [MouseButton; 3] = [MouseButton::Left, MouseButton::Right, MouseButton::Ex0];
+
+    fn arb_event() -> impl Strategy<Value = InputEvent> {
+        let state = prop_oneof![Just(ButtonState::Pressed), Just(ButtonState::Released)];
+        prop_oneof![
+            (0..KEYS.len(), state.clone()).prop_map(|(i, s)| key(KEYS[i], s)),
+            (0..BUTTONS.len(), state).prop_map(|(i, s)| button(BUTTONS[i], s)),
+            (0..8u32).prop_map(|bits| {
+                let buttons = BUTTONS
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| bits & (1 << i) != 0)
+                    .map(|(_, b)| *b)
+                    .collect::<Vec<_>>();
+                InputEvent::CursorMoved(mouse_state(0, 0, &buttons))
+            }),
+            Just(InputEvent::CursorLeft),
+            Just(InputEvent::Inactivated),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn matches_model(events in proptest::collection::vec(arb_event(), 0..64)) {
+            let mut input = InputState::new();
+            let mut keys = HashSet::new();
+            let mut buttons = HashSet::new();
+            for event in events {
+                input.process(event);
+                match event {
+                    InputEvent::Key { key_code, state: ButtonState::Pressed, .. } => {
+                        keys.insert(key_code);
+                    }
+                    InputEvent::Key { key_code, state: ButtonState::Released, .. } => {
+                        keys.remove(&key_code);
+                    }
+                    InputEvent::MouseButton { button, state: ButtonState::Pressed, .. } => {
+                        buttons.insert(button);
+                    }
+                    InputEvent::MouseButton { button, state: ButtonState::Released, .. } => {
+                        buttons.remove(&button);
+                    }
+                    InputEvent::CursorMoved(mouse_state) => {
+                        buttons = mouse_state.buttons.iter().collect();
+                    }
+                    InputEvent::CursorLeft => {}
+                    InputEvent::Inactivated => {
+                        keys.clear();
+                        buttons.clear();
+                    }
+                }
+                for k in KEYS {
+                    prop_assert!(input.is_key_down(k) == keys.contains(&VirtualKeyCode::from(k)));
+                }
+                for b in BUTTONS {
+                    prop_assert!(input.is_button_down(b) == buttons.contains(&b));
+                }
+            }
+            input.process(InputEvent::Inactivated);
+            prop_assert!(input.keys_down().next().is_none());
+            prop_assert!(input.buttons().is_empty());
+            for k in keys {
+                prop_assert!(input.released_keys.contains(&k));
+            }
+            input.end_frame();
+            prop_assert!(KEYS.iter().all(|k| !input.is_key_pressed(*k) && !input.is_key_released(*k)));
+        }
+    }
+}
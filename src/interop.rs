@@ -0,0 +1,248 @@
+use super::*;
+
+#[cfg(feature = "mint")]
+macro_rules! impl_mint {
+    ($unit:ident) => {
+        impl<T> From<mint::Point2<T>> for $unit<Point<T>> {
+            #[inline]
+            fn from(src: mint::Point2<T>) -> Self {
+                $unit(Point::new(src.x, src.y))
+            }
+        }
+
+        impl<T> From<$unit<Point<T>>> for mint::Point2<T> {
+            #[inline]
+            fn from(src: $unit<Point<T>>) -> Self {
+                mint::Point2 {
+                    x: src.0.x,
+                    y: src.0.y,
+                }
+            }
+        }
+
+        impl<T> From<mint::Vector2<T>> for $unit<Vector<T>> {
+            #[inline]
+            fn from(src: mint::Vector2<T>) -> Self {
+                $unit(Vector::new(src.x, src.y))
+            }
+        }
+
+        impl<T> From<$unit<Vector<T>>> for mint::Vector2<T> {
+            #[inline]
+            fn from(src: $unit<Vector<T>>) -> Self {
+                mint::Vector2 {
+                    x: src.0.x,
+                    y: src.0.y,
+                }
+            }
+        }
+
+        impl<T> From<mint::Vector2<T>> for $unit<Size<T>> {
+            #[inline]
+            fn from(src: mint::Vector2<T>) -> Self {
+                $unit(Size::new(src.x, src.y))
+            }
+        }
+
+        impl<T> From<$unit<Size<T>>> for mint::Vector2<T> {
+            #[inline]
+            fn from(src: $unit<Size<T>>) -> Self {
+                mint::Vector2 {
+                    x: src.0.width,
+                    y: src.0.height,
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "mint")]
+impl_mint!(Logical);
+#[cfg(feature = "mint")]
+impl_mint!(Physical);
+#[cfg(feature = "mint")]
+impl_mint!(Screen);
+
+#[cfg(feature = "euclid")]
+macro_rules! impl_euclid {
+    ($unit:ident) => {
+        impl<T, U> From<euclid::Point2D<T, U>> for $unit<Point<T>> {
+            #[inline]
+            fn from(src: euclid::Point2D<T, U>) -> Self {
+                $unit(Point::new(src.x, src.y))
+            }
+        }
+
+        impl<T, U> From<$unit<Point<T>>> for euclid::Point2D<T, U> {
+            #[inline]
+            fn from(src: $unit<Point<T>>) -> Self {
+                euclid::Point2D::new(src.0.x, src.0.y)
+            }
+        }
+
+        impl<T, U> From<euclid::Vector2D<T, U>> for $unit<Vector<T>> {
+            #[inline]
+            fn from(src: euclid::Vector2D<T, U>) -> Self {
+                $unit(Vector::new(src.x, src.y))
+            }
+        }
+
+        impl<T, U> From<$unit<Vector<T>>> for euclid::Vector2D<T, U> {
+            #[inline]
+            fn from(src: $unit<Vector<T>>) -> Self {
+                euclid::Vector2D::new(src.0.x, src.0.y)
+            }
+        }
+
+        impl<T, U> From<euclid::Size2D<T, U>> for $unit<Size<T>> {
+            #[inline]
+            fn from(src: euclid::Size2D<T, U>) -> Self {
+                $unit(Size::new(src.width, src.height))
+            }
+        }
+
+        impl<T, U> From<$unit<Size<T>>> for euclid::Size2D<T, U> {
+            #[inline]
+            fn from(src: $unit<Size<T>>) -> Self {
+                euclid::Size2D::new(src.0.width, src.0.height)
+            }
+        }
+
+        impl<T, U> From<euclid::Rect<T, U>> for $unit<Rect<T>> {
+            #[inline]
+            fn from(src: euclid::Rect<T, U>) -> Self {
+                $unit(Rect::new(
+                    (src.origin.x, src.origin.y),
+                    (src.size.width, src.size.height),
+                ))
+            }
+        }
+
+        impl<T, U> From<$unit<Rect<T>>> for euclid::Rect<T, U> {
+            #[inline]
+            fn from(src: $unit<Rect<T>>) -> Self {
+                euclid::Rect::new(
+                    euclid::Point2D::new(src.0.origin.x, src.0.origin.y),
+                    euclid::Size2D::new(src.0.size.width, src.0.size.height),
+                )
+            }
+        }
+    };
+}
+
+#[cfg(feature = "euclid")]
+impl_euclid!(Logical);
+#[cfg(feature = "euclid")]
+impl_euclid!(Physical);
+#[cfg(feature = "euclid")]
+impl_euclid!(Screen);
+
+#[cfg(feature = "glam")]
+macro_rules! impl_glam {
+    (@impl $unit:ident, $vec:ty, $t:ty) => {
+        impl From<$vec> for $unit<Point<$t>> {
+            #[inline]
+            fn from(src: $vec) -> Self {
+                $unit(Point::new(src.x, src.y))
+            }
+        }
+
+        impl From<$unit<Point<$t>>> for $vec {
+            #[inline]
+            fn from(src: $unit<Point<$t>>) -> Self {
+                <$vec>::new(src.0.x, src.0.y)
+            }
+        }
+
+        impl From<$vec> for $unit<Vector<$t>> {
+            #[inline]
+            fn from(src: $vec) -> Self {
+                $unit(Vector::new(src.x, src.y))
+            }
+        }
+
+        impl From<$unit<Vector<$t>>> for $vec {
+            #[inline]
+            fn from(src: $unit<Vector<$t>>) -> Self {
+                <$vec>::new(src.0.x, src.0.y)
+            }
+        }
+
+        impl From<$vec> for $unit<Size<$t>> {
+            #[inline]
+            fn from(src: $vec) -> Self {
+                $unit(Size::new(src.x, src.y))
+            }
+        }
+
+        impl From<$unit<Size<$t>>> for $vec {
+            #[inline]
+            fn from(src: $unit<Size<$t>>) -> Self {
+                <$vec>::new(src.0.width, src.0.height)
+            }
+        }
+    };
+    ($($unit:ident),*) => {
+        $(
+            impl_glam!(@impl $unit, glam::Vec2, f32);
+            impl_glam!(@impl $unit, glam::DVec2, f64);
+            impl_glam!(@impl $unit, glam::IVec2, i32);
+            impl_glam!(@impl $unit, glam::UVec2, u32);
+        )*
+    };
+}
+
+#[cfg(feature = "glam")]
+impl_glam!(Logical, Physical, Screen);
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[cfg(feature = "mint")]
+    #[test]
+    fn mint() {
+        let src = Logical(Point::new(1, 2));
+        let dst: mint::Point2<i32> = src.into();
+        assert!(dst == mint::Point2 { x: 1, y: 2 });
+        assert!(Logical::<Point<i32>>::from(dst) == src);
+        let src = Physical(Size::new(640u32, 480u32));
+        let dst: mint::Vector2<u32> = src.into();
+        assert!(dst == mint::Vector2 { x: 640, y: 480 });
+        assert!(Physical::<Size<u32>>::from(dst) == src);
+        let dst: mint::Vector2<i32> = Screen(Vector::new(-3, 4)).into();
+        assert!(Screen::<Vector<i32>>::from(dst) == Screen(Vector::new(-3, 4)));
+    }
+
+    #[cfg(feature = "euclid")]
+    #[test]
+    fn euclid() {
+        let src = Physical(Rect::new((10, 20), (30, 40)));
+        let dst: euclid::default::Rect<i32> = src.into();
+        assert!(dst == euclid::rect(10, 20, 30, 40));
+        assert!(Physical::<Rect<i32>>::from(dst) == src);
+        let dst: euclid::default::Point2D<f32> = Logical(Point::new(1.5, 2.5)).into();
+        assert!(dst == euclid::point2(1.5, 2.5));
+        let dst: euclid::default::Size2D<u32> = Logical(Size::new(3, 4)).into();
+        assert!(Logical::<Size<u32>>::from(dst) == Logical(Size::new(3, 4)));
+        let dst: mint::Point2<i32> =
+            euclid::default::Point2D::from(Screen(Point::new(5, 6))).into();
+        assert!(Screen::<Point<i32>>::from(dst) == Screen(Point::new(5, 6)));
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn glam() {
+        let dst: glam::Vec2 = Logical(Point::new(1.0f32, 2.0f32)).into();
+        assert!(dst == glam::Vec2::new(1.0, 2.0));
+        assert!(Logical::<Point<f32>>::from(dst) == Logical(Point::new(1.0, 2.0)));
+        let dst: glam::UVec2 = Physical(Size::new(640u32, 480u32)).into();
+        assert!(Physical::<Size<u32>>::from(dst) == Physical(Size::new(640, 480)));
+        let dst: glam::IVec2 = Screen(Vector::new(-1, 1)).into();
+        assert!(dst == glam::IVec2::new(-1, 1));
+        let dst: glam::DVec2 = Logical(Size::new(0.5f64, 0.25f64)).into();
+        let dst: mint::Vector2<f64> = dst.into();
+        assert!(Logical::<Size<f64>>::from(dst) == Logical(Size::new(0.5, 0.25)));
+    }
+}
@@ -0,0 +1,274 @@
+use super::*;
+
+macro_rules! named_keys {
+    ($($name:ident => [$($vkey:ident),*]),* $(,)?) => {
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub enum NamedKey {
+            $($name,)*
+        }
+
+        impl NamedKey {
+            pub fn from_virtual_key(vkey: VirtualKeyCode) -> Option<Self> {
+                match std::convert::TryFrom::try_from(vkey).ok()? {
+                    $($(VirtualKey::$vkey)|* => Some(Self::$name),)*
+                    _ => None,
+                }
+            }
+
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$name => stringify!($name),)*
+                }
+            }
+        }
+    };
+}
+
+named_keys! {
+    Cancel => [Cancel],
+    Backspace => [BackSpace],
+    Tab => [Tab],
+    Clear => [Clear],
+    Enter => [Enter],
+    Shift => [Shift, LShift, RShift],
+    Control => [Ctrl, LCtrl, RCtrl],
+    Alt => [Alt, LAlt, RAlt],
+    Meta => [LWin, RWin],
+    Pause => [Pause],
+    CapsLock => [CapsLock],
+    KanaMode => [Kana],
+    KanjiMode => [Kanji],
+    JunjaMode => [Junja],
+    FinalMode => [Final],
+    ImeOn => [ImeOn],
+    ImeOff => [ImeOff],
+    Escape => [Esc],
+    Convert => [Convert],
+    NonConvert => [NonConvert],
+    Accept => [Accept],
+    ModeChange => [ModeChange],
+    PageUp => [PageUp],
+    PageDown => [PageDown],
+    End => [End],
+    Home => [Home],
+    ArrowLeft => [Left],
+    ArrowUp => [Up],
+    ArrowRight => [Right],
+    ArrowDown => [Down],
+    Select => [Select],
+    Print => [Print],
+    Execute => [Execute],
+    PrintScreen => [PrintScreen],
+    Insert => [Insert],
+    Delete => [Delete],
+    Help => [Help],
+    ContextMenu => [Apps],
+    Standby => [Sleep],
+    F1 => [F1],
+    F2 => [F2],
+    F3 => [F3],
+    F4 => [F4],
+    F5 => [F5],
+    F6 => [F6],
+    F7 => [F7],
+    F8 => [F8],
+    F9 => [F9],
+    F10 => [F10],
+    F11 => [F11],
+    F12 => [F12],
+    F13 => [F13],
+    F14 => [F14],
+    F15 => [F15],
+    F16 => [F16],
+    F17 => [F17],
+    F18 => [F18],
+    F19 => [F19],
+    F20 => [F20],
+    F21 => [F21],
+    F22 => [F22],
+    F23 => [F23],
+    F24 => [F24],
+    NumLock => [NumLock],
+    ScrollLock => [ScrollLock],
+    BrowserBack => [BrowserBack],
+    BrowserForward => [BrowserForward],
+    BrowserRefresh => [BrowserRefresh],
+    BrowserStop => [BrowserStop],
+    BrowserSearch => [BrowserSearch],
+    BrowserFavorites => [BrowserFavorites],
+    BrowserHome => [BrowserHome],
+    AudioVolumeMute => [VolumeMute],
+    AudioVolumeDown => [VolumeDown],
+    AudioVolumeUp => [VolumeUp],
+    MediaTrackNext => [MediaNextTrack],
+    MediaTrackPrevious => [MediaPrevTrack],
+    MediaStop => [MediaStop],
+    MediaPlayPause => [MediaPlayPause],
+    LaunchMail => [LaunchMail],
+    LaunchMediaPlayer => [LaunchMediaSelect],
+    LaunchApplication1 => [LaunchApp1],
+    LaunchApplication2 => [LaunchApp2],
+    Process => [ProcessKey],
+    Attn => [Attn],
+    CrSel => [CrSel],
+    ExSel => [ExSel],
+    EraseEof => [EraseEof],
+    Play => [Play],
+    ZoomToggle => [Zoom],
+}
+
+impl std::fmt::Display for NamedKey {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Key {
+    Named(NamedKey),
+    Character(String),
+    Dead(char),
+    Unidentified,
+}
+
+impl Key {
+    pub(crate) fn from_utf16(vkey: VirtualKeyCode, len: i32, buffer: &[u16]) -> Self {
+        if let Some(named) = NamedKey::from_virtual_key(vkey) {
+            return Self::Named(named);
+        }
+        if len < 0 {
+            return match char::decode_utf16(buffer.iter().copied()).next() {
+                Some(Ok(c)) => Self::Dead(c),
+                _ => Self::Unidentified,
+            };
+        }
+        match decode(len, buffer) {
+            Some(s) if !s.chars().any(char::is_control) => Self::Character(s),
+            _ => Self::Unidentified,
+        }
+    }
+
+    #[inline]
+    pub fn as_character(&self) -> Option<&str> {
+        match self {
+            Self::Character(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+impl From<NamedKey> for Key {
+    #[inline]
+    fn from(src: NamedKey) -> Self {
+        Self::Named(src)
+    }
+}
+
+impl PartialEq<NamedKey> for Key {
+    #[inline]
+    fn eq(&self, other: &NamedKey) -> bool {
+        matches!(self, Self::Named(named) if named == other)
+    }
+}
+
+impl PartialEq<&str> for Key {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_character() == Some(*other)
+    }
+}
+
+impl std::fmt::Display for Key {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Named(named) => named.fmt(f),
+            Self::Character(s) => f.write_str(s),
+            Self::Dead(c) => write!(f, "Dead({})", c),
+            Self::Unidentified => f.write_str("Unidentified"),
+        }
+    }
+}
+
+pub(crate) fn text_from_utf16(len: i32, buffer: &[u16]) -> Option<String> {
+    let text = decode(len, buffer)?
+        .chars()
+        .filter(|&c| is_text_char(c))
+        .collect::<String>();
+    (!text.is_empty()).then_some(text)
+}
+
+#[inline]
+pub(crate) fn is_text_char(c: char) -> bool {
+    !c.is_control() || c == '\r' || c == '\t'
+}
+
+fn decode(len: i32, buffer: &[u16]) -> Option<String> {
+    if len <= 0 {
+        return None;
+    }
+    let len = (len as usize).min(buffer.len());
+    String::from_utf16(&buffer[..len]).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utf16(s: &str) -> Vec<u16> {
+        s.encode_utf16().collect()
+    }
+
+    #[test]
+    fn named_keys_ignore_text() {
+        let key = Key::from_utf16(VirtualKey::Enter.into(), 1, &utf16("\r"));
+        assert!(key == NamedKey::Enter);
+        let key = Key::from_utf16(VirtualKey::LShift.into(), 0, &[]);
+        assert!(key == NamedKey::Shift);
+        let key = Key::from_utf16(VirtualKey::RWin.into(), 0, &[]);
+        assert!(key == NamedKey::Meta);
+        let key = Key::from_utf16(VirtualKey::F24.into(), 0, &[]);
+        assert!(key == NamedKey::F24);
+    }
+
+    #[test]
+    fn characters() {
+        let key = Key::from_utf16(VirtualKey::A.into(), 1, &utf16("a"));
+        assert!(key == "a");
+        let key = Key::from_utf16(VirtualKey::E.into(), 1, &utf16("é"));
+        assert!(key == Key::Character("é".into()));
+        let key = Key::from_utf16(VirtualKey::Space.into(), 1, &utf16(" "));
+        assert!(key == " ");
+        let buffer = utf16("😀");
+        let key = Key::from_utf16(VirtualKey::Oem1.into(), buffer.len() as _, &buffer);
+        assert!(key == "😀");
+    }
+
+    #[test]
+    fn dead_keys() {
+        let key = Key::from_utf16(VirtualKey::Oem6.into(), -1, &utf16("´"));
+        assert!(key == Key::Dead('´'));
+        let key = Key::from_utf16(VirtualKey::Oem6.into(), -1, &[]);
+        assert!(key == Key::Unidentified);
+    }
+
+    #[test]
+    fn unidentified() {
+        assert!(Key::from_utf16(VirtualKey::A.into(), 0, &[]) == Key::Unidentified);
+        assert!(Key::from_utf16(VirtualKey::A.into(), 1, &[0x01]) == Key::Unidentified);
+        assert!(Key::from_utf16(VirtualKey::A.into(), 1, &[0xd800]) == Key::Unidentified);
+    }
+
+    #[test]
+    fn text() {
+        assert!(text_from_utf16(1, &utf16("a")) == Some("a".into()));
+        assert!(text_from_utf16(2, &utf16("e\u{301}")) == Some("e\u{301}".into()));
+        assert!(text_from_utf16(1, &utf16("\r")) == Some("\r".into()));
+        assert!(text_from_utf16(1, &[0x08]) == None);
+        assert!(text_from_utf16(-1, &utf16("´")) == None);
+        assert!(text_from_utf16(0, &[]) == None);
+        assert!(text_from_utf16(1, &utf16("ab")) == Some("a".into()));
+    }
+}
@@ -1,19 +1,42 @@
+mod accelerator;
+mod appearance;
 mod device;
 mod error;
 pub mod event;
 pub mod geometry;
+mod hit_test;
+mod hotkey;
 pub mod ime;
+pub mod input;
+mod interop;
+mod key;
+mod monitor;
+mod physical_key;
+mod placement;
+mod positioner;
 mod procedure;
 mod resource;
+pub mod system;
+mod text_input;
 mod ui_thread;
 mod utility;
 pub mod window;
 
+pub use accelerator::*;
+pub use appearance::*;
 pub use device::*;
 pub use error::*;
 pub use geometry::*;
+pub use hit_test::*;
+pub use hotkey::*;
+pub use key::*;
+pub use monitor::*;
+pub use physical_key::*;
+pub use placement::*;
+pub use positioner::*;
 pub use resource::*;
 pub use window::Window;
 
+use text_input::TextInput;
 pub use ui_thread::UiThread;
 use ui_thread::{Context, CONTEXT};
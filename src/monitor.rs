@@ -0,0 +1,120 @@
+use super::*;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Monitor {
+    pub name: String,
+    pub rect: ScreenRect<i32>,
+    pub work_area: ScreenRect<i32>,
+    pub dpi: u32,
+    pub refresh_rate: Option<u32>,
+    pub primary: bool,
+}
+
+impl Monitor {
+    #[inline]
+    pub fn scale_factor(&self) -> ScaleFactor {
+        Dpi(self.dpi).scale_factor()
+    }
+
+    #[inline]
+    pub fn contains(&self, pt: &ScreenPoint<i32>) -> bool {
+        self.rect.contains(pt)
+    }
+
+    pub fn nearest<'a>(monitors: &'a [Monitor], pt: &ScreenPoint<i32>) -> Option<&'a Monitor> {
+        let distance = |m: &Monitor| {
+            let axis = |v: i32, min: i32, max: i32| {
+                let v = v as i64;
+                if v < min as i64 {
+                    min as i64 - v
+                } else if v >= max as i64 {
+                    v - max as i64 + 1
+                } else {
+                    0
+                }
+            };
+            let dx = axis(pt.x, m.rect.left(), m.rect.right());
+            let dy = axis(pt.y, m.rect.top(), m.rect.bottom());
+            dx * dx + dy * dy
+        };
+        monitors.iter().min_by_key(|m| distance(m))
+    }
+
+    #[inline]
+    pub fn primary(monitors: &[Monitor]) -> Option<&Monitor> {
+        monitors
+            .iter()
+            .find(|m| m.primary)
+            .or_else(|| monitors.first())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monitor(name: &str, x: i32, y: i32, w: i32, h: i32, dpi: u32, primary: bool) -> Monitor {
+        let rect = Screen(Rect::new((x, y), (w, h)));
+        Monitor {
+            name: name.into(),
+            rect,
+            work_area: Screen(Rect::new((x, y), (w, h - 40))),
+            dpi,
+            refresh_rate: Some(60),
+            primary,
+        }
+    }
+
+    fn layout() -> Vec<Monitor> {
+        vec![
+            monitor("LEFT", -1280, 56, 1280, 1024, 96, false),
+            monitor("MAIN", 0, 0, 1920, 1080, 120, true),
+            monitor("TOP", 320, -1440, 2560, 1440, 144, false),
+        ]
+    }
+
+    #[test]
+    fn scale_factor() {
+        let monitors = layout();
+        assert!(monitors[0].scale_factor() == ScaleFactor(1.0));
+        assert!(monitors[1].scale_factor() == ScaleFactor(1.25));
+        assert!(monitors[2].scale_factor() == ScaleFactor(1.5));
+    }
+
+    #[test]
+    fn contains() {
+        let monitors = layout();
+        assert!(monitors[1].contains(&Screen(Point::new(0, 0))));
+        assert!(!monitors[1].contains(&Screen(Point::new(1920, 0))));
+        assert!(monitors[0].contains(&Screen(Point::new(-1, 56))));
+        assert!(!monitors[0].contains(&Screen(Point::new(-1, 55))));
+    }
+
+    #[test]
+    fn nearest() {
+        let monitors = layout();
+        let name = |x, y| {
+            Monitor::nearest(&monitors, &Screen(Point::new(x, y)))
+                .unwrap()
+                .name
+                .as_str()
+        };
+        assert!(name(100, 100) == "MAIN");
+        assert!(name(-100, 100) == "LEFT");
+        assert!(name(1000, -100) == "TOP");
+        assert!(name(2500, 900) == "MAIN");
+        assert!(name(5000, 500) == "TOP");
+        assert!(name(-100, 0) == "LEFT");
+        assert!(name(0, -1000) == "TOP");
+        assert!(Monitor::nearest(&[], &Screen(Point::new(0, 0))).is_none());
+    }
+
+    #[test]
+    fn primary() {
+        let monitors = layout();
+        assert!(Monitor::primary(&monitors).unwrap().name == "MAIN");
+        assert!(Monitor::primary(&monitors[2..]).unwrap().name == "TOP");
+        assert!(Monitor::primary(&[]).is_none());
+    }
+}
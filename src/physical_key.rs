@@ -0,0 +1,295 @@
+macro_rules! physical_keys {
+    ($(($name:ident, $scan_code:expr, $extended:expr)),* $(,)?) => {
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub enum PhysicalKey {
+            $($name,)*
+            Unidentified { scan_code: u32, extended: bool },
+        }
+
+        impl PhysicalKey {
+            pub const ALL: &'static [PhysicalKey] = &[$(PhysicalKey::$name),*];
+
+            pub fn from_scan_code(scan_code: u32, extended: bool) -> Self {
+                match (scan_code, extended) {
+                    $(($scan_code, $extended) => Self::$name,)*
+                    _ => Self::Unidentified {
+                        scan_code,
+                        extended,
+                    },
+                }
+            }
+
+            pub fn scan_code(&self) -> (u32, bool) {
+                match *self {
+                    $(Self::$name => ($scan_code, $extended),)*
+                    Self::Unidentified {
+                        scan_code,
+                        extended,
+                    } => (scan_code, extended),
+                }
+            }
+
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$name => stringify!($name),)*
+                    Self::Unidentified { .. } => "Unidentified",
+                }
+            }
+        }
+
+        impl std::str::FromStr for PhysicalKey {
+            type Err = ParsePhysicalKeyError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $(stringify!($name) => Ok(Self::$name),)*
+                    _ => Err(ParsePhysicalKeyError(s.into())),
+                }
+            }
+        }
+    };
+}
+
+physical_keys! {
+    (Escape, 0x01, false),
+    (Digit1, 0x02, false),
+    (Digit2, 0x03, false),
+    (Digit3, 0x04, false),
+    (Digit4, 0x05, false),
+    (Digit5, 0x06, false),
+    (Digit6, 0x07, false),
+    (Digit7, 0x08, false),
+    (Digit8, 0x09, false),
+    (Digit9, 0x0a, false),
+    (Digit0, 0x0b, false),
+    (Minus, 0x0c, false),
+    (Equal, 0x0d, false),
+    (Backspace, 0x0e, false),
+    (Tab, 0x0f, false),
+    (KeyQ, 0x10, false),
+    (KeyW, 0x11, false),
+    (KeyE, 0x12, false),
+    (KeyR, 0x13, false),
+    (KeyT, 0x14, false),
+    (KeyY, 0x15, false),
+    (KeyU, 0x16, false),
+    (KeyI, 0x17, false),
+    (KeyO, 0x18, false),
+    (KeyP, 0x19, false),
+    (BracketLeft, 0x1a, false),
+    (BracketRight, 0x1b, false),
+    (Enter, 0x1c, false),
+    (ControlLeft, 0x1d, false),
+    (KeyA, 0x1e, false),
+    (KeyS, 0x1f, false),
+    (KeyD, 0x20, false),
+    (KeyF, 0x21, false),
+    (KeyG, 0x22, false),
+    (KeyH, 0x23, false),
+    (KeyJ, 0x24, false),
+    (KeyK, 0x25, false),
+    (KeyL, 0x26, false),
+    (Semicolon, 0x27, false),
+    (Quote, 0x28, false),
+    (Backquote, 0x29, false),
+    (ShiftLeft, 0x2a, false),
+    (Backslash, 0x2b, false),
+    (KeyZ, 0x2c, false),
+    (KeyX, 0x2d, false),
+    (KeyC, 0x2e, false),
+    (KeyV, 0x2f, false),
+    (KeyB, 0x30, false),
+    (KeyN, 0x31, false),
+    (KeyM, 0x32, false),
+    (Comma, 0x33, false),
+    (Period, 0x34, false),
+    (Slash, 0x35, false),
+    (ShiftRight, 0x36, false),
+    (NumpadMultiply, 0x37, false),
+    (AltLeft, 0x38, false),
+    (Space, 0x39, false),
+    (CapsLock, 0x3a, false),
+    (F1, 0x3b, false),
+    (F2, 0x3c, false),
+    (F3, 0x3d, false),
+    (F4, 0x3e, false),
+    (F5, 0x3f, false),
+    (F6, 0x40, false),
+    (F7, 0x41, false),
+    (F8, 0x42, false),
+    (F9, 0x43, false),
+    (F10, 0x44, false),
+    (Pause, 0x45, false),
+    (ScrollLock, 0x46, false),
+    (Numpad7, 0x47, false),
+    (Numpad8, 0x48, false),
+    (Numpad9, 0x49, false),
+    (NumpadSubtract, 0x4a, false),
+    (Numpad4, 0x4b, false),
+    (Numpad5, 0x4c, false),
+    (Numpad6, 0x4d, false),
+    (NumpadAdd, 0x4e, false),
+    (Numpad1, 0x4f, false),
+    (Numpad2, 0x50, false),
+    (Numpad3, 0x51, false),
+    (Numpad0, 0x52, false),
+    (NumpadDecimal, 0x53, false),
+    (IntlBackslash, 0x56, false),
+    (F11, 0x57, false),
+    (F12, 0x58, false),
+    (NumpadEqual, 0x59, false),
+    (F13, 0x64, false),
+    (F14, 0x65, false),
+    (F15, 0x66, false),
+    (F16, 0x67, false),
+    (F17, 0x68, false),
+    (F18, 0x69, false),
+    (F19, 0x6a, false),
+    (F20, 0x6b, false),
+    (F21, 0x6c, false),
+    (F22, 0x6d, false),
+    (F23, 0x6e, false),
+    (KanaMode, 0x70, false),
+    (Lang2, 0x71, false),
+    (Lang1, 0x72, false),
+    (IntlRo, 0x73, false),
+    (F24, 0x76, false),
+    (Convert, 0x79, false),
+    (NonConvert, 0x7b, false),
+    (IntlYen, 0x7d, false),
+    (NumpadComma, 0x7e, false),
+    (MediaTrackPrevious, 0x10, true),
+    (MediaTrackNext, 0x19, true),
+    (NumpadEnter, 0x1c, true),
+    (ControlRight, 0x1d, true),
+    (AudioVolumeMute, 0x20, true),
+    (LaunchApp2, 0x21, true),
+    (MediaPlayPause, 0x22, true),
+    (MediaStop, 0x24, true),
+    (AudioVolumeDown, 0x2e, true),
+    (AudioVolumeUp, 0x30, true),
+    (BrowserHome, 0x32, true),
+    (NumpadDivide, 0x35, true),
+    (PrintScreen, 0x37, true),
+    (AltRight, 0x38, true),
+    (NumLock, 0x45, true),
+    (Home, 0x47, true),
+    (ArrowUp, 0x48, true),
+    (PageUp, 0x49, true),
+    (ArrowLeft, 0x4b, true),
+    (ArrowRight, 0x4d, true),
+    (End, 0x4f, true),
+    (ArrowDown, 0x50, true),
+    (PageDown, 0x51, true),
+    (Insert, 0x52, true),
+    (Delete, 0x53, true),
+    (MetaLeft, 0x5b, true),
+    (MetaRight, 0x5c, true),
+    (ContextMenu, 0x5d, true),
+    (Power, 0x5e, true),
+    (Sleep, 0x5f, true),
+    (WakeUp, 0x63, true),
+    (BrowserSearch, 0x65, true),
+    (BrowserFavorites, 0x66, true),
+    (BrowserRefresh, 0x67, true),
+    (BrowserStop, 0x68, true),
+    (BrowserForward, 0x69, true),
+    (BrowserBack, 0x6a, true),
+    (LaunchApp1, 0x6b, true),
+    (LaunchMail, 0x6c, true),
+    (MediaSelect, 0x6d, true),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, thiserror::Error)]
+#[error("unknown physical key: {0}")]
+pub struct ParsePhysicalKeyError(String);
+
+impl std::fmt::Display for PhysicalKey {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn all_scan_codes_round_trip() {
+        for scan_code in 0..=0xffu32 {
+            for extended in [false, true] {
+                let key = PhysicalKey::from_scan_code(scan_code, extended);
+                assert!(key.scan_code() == (scan_code, extended), "{:?}", key);
+            }
+        }
+    }
+
+    #[test]
+    fn table_is_injective() {
+        let scan_codes = PhysicalKey::ALL
+            .iter()
+            .map(|key| key.scan_code())
+            .collect::<HashSet<_>>();
+        assert!(scan_codes.len() == PhysicalKey::ALL.len());
+        let names = PhysicalKey::ALL
+            .iter()
+            .map(|key| key.as_str())
+            .collect::<HashSet<_>>();
+        assert!(names.len() == PhysicalKey::ALL.len());
+    }
+
+    #[test]
+    fn identified_keys_round_trip() {
+        for key in PhysicalKey::ALL {
+            let (scan_code, extended) = key.scan_code();
+            assert!(PhysicalKey::from_scan_code(scan_code, extended) == *key);
+            assert!(key.as_str().parse::<PhysicalKey>().unwrap() == *key);
+            assert!(key.to_string() == key.as_str());
+        }
+    }
+
+    #[test]
+    fn extended_flag_distinguishes_keys() {
+        let pairs = [
+            (PhysicalKey::Enter, PhysicalKey::NumpadEnter),
+            (PhysicalKey::ControlLeft, PhysicalKey::ControlRight),
+            (PhysicalKey::AltLeft, PhysicalKey::AltRight),
+            (PhysicalKey::Slash, PhysicalKey::NumpadDivide),
+            (PhysicalKey::NumpadMultiply, PhysicalKey::PrintScreen),
+            (PhysicalKey::Pause, PhysicalKey::NumLock),
+            (PhysicalKey::Numpad7, PhysicalKey::Home),
+            (PhysicalKey::Numpad8, PhysicalKey::ArrowUp),
+            (PhysicalKey::NumpadDecimal, PhysicalKey::Delete),
+        ];
+        for (plain, extended) in pairs {
+            let (scan_code, _) = plain.scan_code();
+            assert!(PhysicalKey::from_scan_code(scan_code, false) == plain);
+            assert!(PhysicalKey::from_scan_code(scan_code, true) == extended);
+        }
+    }
+
+    #[test]
+    fn wasd() {
+        assert!(PhysicalKey::from_scan_code(0x11, false) == PhysicalKey::KeyW);
+        assert!(PhysicalKey::from_scan_code(0x1e, false) == PhysicalKey::KeyA);
+        assert!(PhysicalKey::from_scan_code(0x1f, false) == PhysicalKey::KeyS);
+        assert!(PhysicalKey::from_scan_code(0x20, false) == PhysicalKey::KeyD);
+    }
+
+    #[test]
+    fn unidentified() {
+        let key = PhysicalKey::from_scan_code(0x00, false);
+        assert!(
+            key == PhysicalKey::Unidentified {
+                scan_code: 0,
+                extended: false
+            }
+        );
+        assert!(key.as_str() == "Unidentified");
+        assert!("Unidentified".parse::<PhysicalKey>().is_err());
+        assert!("KeyW".parse::<PhysicalKey>() == Ok(PhysicalKey::KeyW));
+    }
+}
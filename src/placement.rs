@@ -0,0 +1,284 @@
+use super::*;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ShowState {
+    Normal,
+    Minimized,
+    Maximized,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindowPlacement {
+    pub position: ScreenPoint<i32>,
+    pub size: PhysicalSize<u32>,
+    pub show_state: ShowState,
+    pub monitor: Option<String>,
+    pub monitor_rect: Option<ScreenRect<i32>>,
+    pub dpi: u32,
+}
+
+fn scale(value: i64, to: u32, from: u32) -> i64 {
+    (value * to as i64 + from as i64 / 2) / from as i64
+}
+
+fn intersection_area(placement: &WindowPlacement, monitor: &Monitor) -> i64 {
+    let area = &monitor.work_area;
+    let left = (placement.position.x as i64).max(area.left() as i64);
+    let top = (placement.position.y as i64).max(area.top() as i64);
+    let right =
+        (placement.position.x as i64 + placement.size.width as i64).min(area.right() as i64);
+    let bottom =
+        (placement.position.y as i64 + placement.size.height as i64).min(area.bottom() as i64);
+    if left < right && top < bottom {
+        (right - left) * (bottom - top)
+    } else {
+        0
+    }
+}
+
+fn clamp_axis(position: i64, len: i64, area_position: i64, area_len: i64) -> (i64, i64) {
+    let len = len.min(area_len);
+    let position = position
+        .max(area_position)
+        .min(area_position + area_len - len);
+    (position, len)
+}
+
+impl WindowPlacement {
+    pub fn fit(&self, monitors: &[Monitor]) -> Self {
+        enum Found {
+            Named,
+            Intersected,
+            Fallback,
+        }
+
+        let named = self
+            .monitor_rect
+            .and_then(|rect| monitors.iter().find(|m| m.rect == rect))
+            .or_else(|| {
+                self.monitor
+                    .as_ref()
+                    .and_then(|name| monitors.iter().find(|m| &m.name == name))
+            })
+            .map(|m| (m, Found::Named));
+        let intersected = || {
+            monitors
+                .iter()
+                .map(|m| (m, intersection_area(self, m)))
+                .filter(|(_, area)| *area > 0)
+                .max_by_key(|(_, area)| *area)
+                .map(|(m, _)| (m, Found::Intersected))
+        };
+        let fallback = || Monitor::primary(monitors).map(|m| (m, Found::Fallback));
+        let (monitor, found) = match named.or_else(intersected).or_else(fallback) {
+            Some(ret) => ret,
+            None => return self.clone(),
+        };
+        let src_dpi = if self.dpi == 0 {
+            DEFAULT_DPI as u32
+        } else {
+            self.dpi
+        };
+        let dpi = if monitor.dpi == 0 {
+            DEFAULT_DPI as u32
+        } else {
+            monitor.dpi
+        };
+        let width = scale(self.size.width as i64, dpi, src_dpi);
+        let height = scale(self.size.height as i64, dpi, src_dpi);
+        let area_x = monitor.work_area.origin.x as i64;
+        let area_y = monitor.work_area.origin.y as i64;
+        let area_width = monitor.work_area.size.width as i64;
+        let area_height = monitor.work_area.size.height as i64;
+        let (x, y) = match found {
+            Found::Named => (
+                area_x + scale(self.position.x as i64 - area_x, dpi, src_dpi),
+                area_y + scale(self.position.y as i64 - area_y, dpi, src_dpi),
+            ),
+            Found::Intersected => (self.position.x as i64, self.position.y as i64),
+            Found::Fallback => (
+                area_x + (area_width - width.min(area_width)) / 2,
+                area_y + (area_height - height.min(area_height)) / 2,
+            ),
+        };
+        let (x, width) = clamp_axis(x, width, area_x, area_width);
+        let (y, height) = clamp_axis(y, height, area_y, area_height);
+        Self {
+            position: Screen(Point::new(x as i32, y as i32)),
+            size: Physical(Size::new(width as u32, height as u32)),
+            show_state: self.show_state,
+            monitor: Some(monitor.name.clone()),
+            monitor_rect: Some(monitor.rect),
+            dpi,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monitor(name: &str, x: i32, y: i32, w: i32, h: i32, dpi: u32, primary: bool) -> Monitor {
+        Monitor {
+            name: name.into(),
+            rect: Screen(Rect::new((x, y), (w, h + 40))),
+            work_area: Screen(Rect::new((x, y), (w, h))),
+            dpi,
+            refresh_rate: None,
+            primary,
+        }
+    }
+
+    fn placement(
+        x: i32,
+        y: i32,
+        w: u32,
+        h: u32,
+        monitor: Option<&str>,
+        dpi: u32,
+    ) -> WindowPlacement {
+        WindowPlacement {
+            position: Screen(Point::new(x, y)),
+            size: Physical(Size::new(w, h)),
+            show_state: ShowState::Normal,
+            monitor: monitor.map(|m| m.into()),
+            monitor_rect: None,
+            dpi,
+        }
+    }
+
+    fn dual() -> Vec<Monitor> {
+        vec![
+            monitor("DISPLAY1", 0, 0, 1920, 1040, 96, true),
+            monitor("DISPLAY2", 1920, 0, 2560, 1400, 144, false),
+        ]
+    }
+
+    #[test]
+    fn unchanged_on_same_monitor() {
+        let mut src = placement(100, 100, 800, 600, Some("DISPLAY1"), 96);
+        src.monitor_rect = Some(dual()[0].rect);
+        assert!(src.fit(&dual()) == src);
+        let mut src = placement(2000, 50, 1200, 900, Some("DISPLAY2"), 144);
+        src.monitor_rect = Some(dual()[1].rect);
+        assert!(src.fit(&dual()) == src);
+    }
+
+    #[test]
+    fn no_monitors() {
+        let src = placement(-5000, -5000, 800, 600, None, 96);
+        assert!(src.fit(&[]) == src);
+    }
+
+    #[test]
+    fn dpi_changed() {
+        let monitors = vec![monitor("DISPLAY1", 0, 0, 1920, 1040, 192, true)];
+        let ret = placement(100, 50, 400, 300, Some("DISPLAY1"), 96).fit(&monitors);
+        assert!(ret.position.x == 200 && ret.position.y == 100);
+        assert!(ret.size.width == 800 && ret.size.height == 600);
+        assert!(ret.dpi == 192);
+    }
+
+    #[test]
+    fn dpi_changed_with_clamping() {
+        let monitors = vec![monitor("DISPLAY1", 0, 0, 1920, 1040, 96, true)];
+        let ret = placement(1000, 600, 1600, 1000, Some("DISPLAY1"), 144).fit(&monitors);
+        assert!(ret.size.width == 1067 && ret.size.height == 667);
+        assert!(ret.position.x == 667 && ret.position.y == 373);
+    }
+
+    #[test]
+    fn monitor_gone_centers_on_primary() {
+        let monitors = vec![monitor("DISPLAY1", 0, 0, 1920, 1040, 96, true)];
+        let ret = placement(2100, 100, 800, 600, Some("DISPLAY2"), 96).fit(&monitors);
+        assert!(ret.position.x == 560 && ret.position.y == 220);
+        assert!(ret.size.width == 800 && ret.size.height == 600);
+        assert!(ret.monitor.as_deref() == Some("DISPLAY1"));
+    }
+
+    #[test]
+    fn monitor_gone_but_intersects_another() {
+        let ret = placement(1800, 100, 400, 300, Some("DISPLAY3"), 144).fit(&dual());
+        assert!(ret.monitor.as_deref() == Some("DISPLAY2"));
+        assert!(ret.position.x == 1920 && ret.position.y == 100);
+        assert!(ret.size.width == 400 && ret.size.height == 300);
+    }
+
+    #[test]
+    fn unnamed_picks_largest_intersection() {
+        let ret = placement(1700, 0, 400, 300, None, 96).fit(&dual());
+        assert!(ret.monitor.as_deref() == Some("DISPLAY1"));
+        assert!(ret.position.x == 1520 && ret.position.y == 0);
+        let ret = placement(1800, 0, 400, 300, None, 144).fit(&dual());
+        assert!(ret.monitor.as_deref() == Some("DISPLAY2"));
+        assert!(ret.position.x == 1920 && ret.position.y == 0);
+    }
+
+    #[test]
+    fn falls_back_to_first_without_primary() {
+        let monitors = vec![
+            monitor("A", -1280, 0, 1280, 1024, 96, false),
+            monitor("B", 0, 0, 1920, 1080, 96, false),
+        ];
+        let ret = placement(10000, 10000, 640, 480, None, 96).fit(&monitors);
+        assert!(ret.monitor.as_deref() == Some("A"));
+        assert!(ret.position.x == -960 && ret.position.y == 272);
+    }
+
+    #[test]
+    fn oversized_window_is_shrunk() {
+        let monitors = vec![monitor("DISPLAY1", 0, 0, 1280, 720, 96, true)];
+        let ret = placement(-100, -100, 4000, 3000, Some("DISPLAY1"), 96).fit(&monitors);
+        assert!(ret.position.x == 0 && ret.position.y == 0);
+        assert!(ret.size.width == 1280 && ret.size.height == 720);
+    }
+
+    #[test]
+    fn vertical_layout_with_negative_coordinates() {
+        let monitors = vec![
+            monitor("TOP", 0, -1080, 1920, 1080, 96, false),
+            monitor("BOTTOM", 0, 0, 1920, 1040, 96, true),
+        ];
+        let ret = placement(100, -500, 800, 600, None, 96).fit(&monitors);
+        assert!(ret.monitor.as_deref() == Some("TOP"));
+        assert!(ret.position.x == 100 && ret.position.y == -600);
+    }
+
+    #[test]
+    fn monitor_rect_wins_over_reassigned_name() {
+        let mut src = placement(2000, 50, 1200, 900, Some("DISPLAY1"), 144);
+        src.monitor_rect = Some(Screen(Rect::new((1920, 0), (2560, 1440))));
+        let monitors = vec![
+            monitor("DISPLAY2", 0, 0, 1920, 1040, 96, true),
+            monitor("DISPLAY1", 1920, 0, 2560, 1400, 144, false),
+        ];
+        let ret = src.fit(&monitors);
+        assert!(ret.position == src.position && ret.size == src.size);
+        assert!(ret.monitor.as_deref() == Some("DISPLAY1"));
+        let mut src = placement(100, 100, 800, 600, Some("DISPLAY1"), 96);
+        src.monitor_rect = Some(Screen(Rect::new((0, 0), (1920, 1080))));
+        let ret = src.fit(&monitors);
+        assert!(ret.monitor.as_deref() == Some("DISPLAY2"));
+        assert!(ret.monitor_rect == src.monitor_rect);
+        assert!(ret.position == src.position && ret.size == src.size);
+    }
+
+    #[test]
+    fn monitor_rect_changed_falls_back_to_name() {
+        let mut src = placement(100, 50, 400, 300, Some("DISPLAY1"), 96);
+        src.monitor_rect = Some(Screen(Rect::new((0, 0), (1280, 760))));
+        let ret = src.fit(&dual());
+        assert!(ret.monitor.as_deref() == Some("DISPLAY1"));
+        assert!(ret.monitor_rect == Some(Screen(Rect::new((0, 0), (1920, 1080)))));
+        assert!(ret.position.x == 100 && ret.position.y == 50);
+    }
+
+    #[test]
+    fn keeps_show_state() {
+        let mut src = placement(100, 100, 800, 600, Some("DISPLAY1"), 96);
+        src.show_state = ShowState::Maximized;
+        assert!(src.fit(&dual()).show_state == ShowState::Maximized);
+    }
+}
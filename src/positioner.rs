@@ -0,0 +1,369 @@
+use super::*;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Side {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl Side {
+    #[inline]
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Bottom => Self::Top,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+
+    #[inline]
+    fn is_vertical(self) -> bool {
+        matches!(self, Self::Top | Self::Bottom)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Alignment {
+    Start,
+    Center,
+    End,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Span {
+    position: i64,
+    len: i64,
+}
+
+impl Span {
+    #[inline]
+    fn end(&self) -> i64 {
+        self.position + self.len
+    }
+
+    #[inline]
+    fn overflow(&self, bounds: &Span) -> i64 {
+        (bounds.position - self.position).max(0) + (self.end() - bounds.end()).max(0)
+    }
+
+    #[inline]
+    fn slide(&self, bounds: &Span) -> Span {
+        let position = self
+            .position
+            .min(bounds.end() - self.len)
+            .max(bounds.position);
+        Span {
+            position,
+            len: self.len,
+        }
+    }
+
+    #[inline]
+    fn resize(&self, bounds: &Span) -> Span {
+        let position = self.position.max(bounds.position);
+        let end = self.end().min(bounds.end()).max(position);
+        Span {
+            position,
+            len: end - position,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Positioner {
+    size: PhysicalSize<u32>,
+    side: Side,
+    alignment: Alignment,
+    offset: ScreenVector<i32>,
+    flip: bool,
+    slide: bool,
+    resize: bool,
+}
+
+impl Positioner {
+    #[inline]
+    pub fn new(size: PhysicalSize<u32>) -> Self {
+        Self {
+            size,
+            side: Side::Bottom,
+            alignment: Alignment::Start,
+            offset: Screen(Vector::new(0, 0)),
+            flip: true,
+            slide: true,
+            resize: false,
+        }
+    }
+
+    #[inline]
+    pub fn side(mut self, side: Side) -> Self {
+        self.side = side;
+        self
+    }
+
+    #[inline]
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    #[inline]
+    pub fn offset(mut self, offset: ScreenVector<i32>) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    #[inline]
+    pub fn flip(mut self, flag: bool) -> Self {
+        self.flip = flag;
+        self
+    }
+
+    #[inline]
+    pub fn slide(mut self, flag: bool) -> Self {
+        self.slide = flag;
+        self
+    }
+
+    #[inline]
+    pub fn resize(mut self, flag: bool) -> Self {
+        self.resize = flag;
+        self
+    }
+
+    fn main_span(&self, side: Side, anchor: &ScreenRect<i32>) -> Span {
+        let (len, offset) = if side.is_vertical() {
+            (self.size.height as i64, self.offset.y as i64)
+        } else {
+            (self.size.width as i64, self.offset.x as i64)
+        };
+        let position = match side {
+            Side::Top => anchor.top() as i64 - len - offset,
+            Side::Bottom => anchor.bottom() as i64 + offset,
+            Side::Left => anchor.left() as i64 - len - offset,
+            Side::Right => anchor.right() as i64 + offset,
+        };
+        Span { position, len }
+    }
+
+    fn cross_span(&self, anchor: &ScreenRect<i32>) -> Span {
+        let (start, end, len, offset) = if self.side.is_vertical() {
+            (
+                anchor.left() as i64,
+                anchor.right() as i64,
+                self.size.width as i64,
+                self.offset.x as i64,
+            )
+        } else {
+            (
+                anchor.top() as i64,
+                anchor.bottom() as i64,
+                self.size.height as i64,
+                self.offset.y as i64,
+            )
+        };
+        let position = match self.alignment {
+            Alignment::Start => start,
+            Alignment::Center => start + (end - start - len) / 2,
+            Alignment::End => end - len,
+        };
+        Span {
+            position: position + offset,
+            len,
+        }
+    }
+
+    pub fn place(&self, anchor: ScreenRect<i32>, bounds: ScreenRect<i32>) -> ScreenRect<i32> {
+        let (main_bounds, cross_bounds) = if self.side.is_vertical() {
+            (
+                Span {
+                    position: bounds.top() as i64,
+                    len: bounds.size.height as i64,
+                },
+                Span {
+                    position: bounds.left() as i64,
+                    len: bounds.size.width as i64,
+                },
+            )
+        } else {
+            (
+                Span {
+                    position: bounds.left() as i64,
+                    len: bounds.size.width as i64,
+                },
+                Span {
+                    position: bounds.top() as i64,
+                    len: bounds.size.height as i64,
+                },
+            )
+        };
+        let mut main = self.main_span(self.side, &anchor);
+        if self.flip && main.overflow(&main_bounds) > 0 {
+            let flipped = self.main_span(self.side.opposite(), &anchor);
+            if flipped.overflow(&main_bounds) < main.overflow(&main_bounds) {
+                main = flipped;
+            }
+        }
+        let mut cross = self.cross_span(&anchor);
+        if self.slide {
+            main = main.slide(&main_bounds);
+            cross = cross.slide(&cross_bounds);
+        }
+        if self.resize {
+            main = main.resize(&main_bounds);
+            cross = cross.resize(&cross_bounds);
+        }
+        let (x, y) = if self.side.is_vertical() {
+            (cross, main)
+        } else {
+            (main, cross)
+        };
+        Screen(Rect::new(
+            (x.position as i32, y.position as i32),
+            (x.len as i32, y.len as i32),
+        ))
+    }
+
+    pub fn place_on(&self, anchor: ScreenRect<i32>, monitors: &[Monitor]) -> ScreenRect<i32> {
+        let center = Screen(Point::new(
+            anchor.left() + anchor.size.width / 2,
+            anchor.top() + anchor.size.height / 2,
+        ));
+        match Monitor::nearest(monitors, &center) {
+            Some(monitor) => self.place(anchor, monitor.work_area),
+            None => self
+                .flip(false)
+                .slide(false)
+                .resize(false)
+                .place(anchor, anchor),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bounds() -> ScreenRect<i32> {
+        Screen(Rect::new((0, 0), (1920, 1040)))
+    }
+
+    fn anchor(x: i32, y: i32, w: i32, h: i32) -> ScreenRect<i32> {
+        Screen(Rect::new((x, y), (w, h)))
+    }
+
+    fn rect(x: i32, y: i32, w: i32, h: i32) -> ScreenRect<i32> {
+        Screen(Rect::new((x, y), (w, h)))
+    }
+
+    fn positioner(w: u32, h: u32) -> Positioner {
+        Positioner::new(Physical(Size::new(w, h)))
+    }
+
+    #[test]
+    fn sides() {
+        let a = anchor(500, 500, 100, 20);
+        let p = positioner(200, 100);
+        assert!(p.place(a, bounds()) == rect(500, 520, 200, 100));
+        assert!(p.side(Side::Top).place(a, bounds()) == rect(500, 400, 200, 100));
+        assert!(p.side(Side::Left).place(a, bounds()) == rect(300, 500, 200, 100));
+        assert!(p.side(Side::Right).place(a, bounds()) == rect(600, 500, 200, 100));
+    }
+
+    #[test]
+    fn alignments() {
+        let a = anchor(500, 500, 100, 20);
+        let p = positioner(200, 100);
+        assert!(p.alignment(Alignment::Center).place(a, bounds()) == rect(450, 520, 200, 100));
+        assert!(p.alignment(Alignment::End).place(a, bounds()) == rect(400, 520, 200, 100));
+        let p = p.side(Side::Right);
+        assert!(p.alignment(Alignment::Center).place(a, bounds()) == rect(600, 460, 200, 100));
+        assert!(p.alignment(Alignment::End).place(a, bounds()) == rect(600, 420, 200, 100));
+    }
+
+    #[test]
+    fn offset() {
+        let a = anchor(500, 500, 100, 20);
+        let p = positioner(200, 100).offset(Screen(Vector::new(10, 4)));
+        assert!(p.place(a, bounds()) == rect(510, 524, 200, 100));
+        assert!(p.side(Side::Top).place(a, bounds()) == rect(510, 396, 200, 100));
+        assert!(p.side(Side::Left).place(a, bounds()) == rect(290, 504, 200, 100));
+    }
+
+    #[test]
+    fn flip() {
+        let a = anchor(500, 1000, 100, 20);
+        let p = positioner(200, 100);
+        assert!(p.place(a, bounds()) == rect(500, 900, 200, 100));
+        let a = anchor(1800, 500, 100, 20);
+        let p = p.side(Side::Right);
+        assert!(p.place(a, bounds()) == rect(1600, 500, 200, 100));
+    }
+
+    #[test]
+    fn flip_keeps_gap() {
+        let a = anchor(500, 1000, 100, 20);
+        let p = positioner(200, 100).offset(Screen(Vector::new(0, 4)));
+        assert!(p.place(a, bounds()) == rect(500, 896, 200, 100));
+    }
+
+    #[test]
+    fn no_flip_when_opposite_is_worse() {
+        let bounds = rect(0, 0, 1920, 200);
+        let a = anchor(500, 50, 100, 20);
+        let p = positioner(200, 160).slide(false);
+        assert!(p.place(a, bounds) == rect(500, 70, 200, 160));
+    }
+
+    #[test]
+    fn slide() {
+        let a = anchor(1850, 500, 60, 20);
+        let p = positioner(200, 100);
+        assert!(p.place(a, bounds()) == rect(1720, 520, 200, 100));
+        assert!(p.slide(false).place(a, bounds()) == rect(1850, 520, 200, 100));
+        let a = anchor(-50, 500, 60, 20);
+        assert!(p.alignment(Alignment::End).place(a, bounds()) == rect(0, 520, 200, 100));
+    }
+
+    #[test]
+    fn resize() {
+        let a = anchor(500, 500, 100, 20);
+        let p = positioner(3000, 100).resize(true);
+        assert!(p.place(a, bounds()) == rect(0, 520, 1920, 100));
+        let p = positioner(3000, 100).slide(false).resize(true);
+        assert!(p.place(a, bounds()) == rect(500, 520, 1420, 100));
+    }
+
+    #[test]
+    fn place_on_monitors() {
+        let monitors = vec![
+            Monitor {
+                name: "MAIN".into(),
+                rect: rect(0, 0, 1920, 1080),
+                work_area: rect(0, 0, 1920, 1040),
+                dpi: 96,
+                refresh_rate: None,
+                primary: true,
+            },
+            Monitor {
+                name: "RIGHT".into(),
+                rect: rect(1920, 0, 1280, 1024),
+                work_area: rect(1920, 0, 1280, 1024),
+                dpi: 96,
+                refresh_rate: None,
+                primary: false,
+            },
+        ];
+        let p = positioner(200, 100);
+        let a = anchor(1900, 1000, 10, 20);
+        assert!(p.place_on(a, &monitors) == rect(1720, 900, 200, 100));
+        let a = anchor(1930, 1000, 10, 20);
+        assert!(p.place_on(a, &monitors) == rect(1930, 900, 200, 100));
+        assert!(p.place_on(a, &[]) == rect(1930, 1020, 200, 100));
+    }
+}
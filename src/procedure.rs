@@ -12,6 +12,8 @@ use windows::Win32::{
     },
 };
 
+pub(crate) const WM_AWITA_DRAG: u32 = WM_APP + 2;
+
 fn context() -> Rc<Context> {
     CONTEXT.with(|ctx| ctx.borrow().as_ref().unwrap().clone())
 }
@@ -74,6 +76,82 @@ fn get_wheel_delta(wparam: WPARAM) -> i16 {
     hiword(wparam.0 as _)
 }
 
+fn current_mouse_buttons() -> MouseButtons {
+    let keys = [
+        (VK_LBUTTON, MouseButton::Left),
+        (VK_RBUTTON, MouseButton::Right),
+        (VK_MBUTTON, MouseButton::Middle),
+        (VK_XBUTTON1, MouseButton::Ex0),
+        (VK_XBUTTON2, MouseButton::Ex1),
+    ];
+    let buttons = keys.iter().fold(0, |buttons, (vkey, button)| unsafe {
+        if GetKeyState(*vkey as _) < 0 {
+            buttons | *button as u32
+        } else {
+            buttons
+        }
+    });
+    buttons.into()
+}
+
+fn current_modifiers() -> Modifiers {
+    let keys = [
+        (VK_LSHIFT, Modifier::LShift),
+        (VK_RSHIFT, Modifier::RShift),
+        (VK_LCONTROL, Modifier::LCtrl),
+        (VK_RCONTROL, Modifier::RCtrl),
+        (VK_LMENU, Modifier::LAlt),
+        (VK_RMENU, Modifier::RAlt),
+        (VK_LWIN, Modifier::LWin),
+        (VK_RWIN, Modifier::RWin),
+    ];
+    keys.iter()
+        .fold(Modifiers::default(), |modifiers, (vkey, modifier)| unsafe {
+            if GetKeyState(*vkey as _) < 0 {
+                modifiers | *modifier
+            } else {
+                modifiers
+            }
+        })
+}
+
+fn current_lock_state() -> LockState {
+    let toggled = |vkey: VIRTUAL_KEY| unsafe { GetKeyState(vkey as _) & 0x01 != 0 };
+    LockState {
+        caps_lock: toggled(VK_CAPITAL),
+        num_lock: toggled(VK_NUMLOCK),
+        scroll_lock: toggled(VK_SCROLL),
+    }
+}
+
+fn mouse_state(position: PhysicalPoint<i32>, buttons: MouseButtons) -> MouseState {
+    MouseState {
+        position,
+        buttons,
+        modifiers: current_modifiers(),
+        lock_state: current_lock_state(),
+    }
+}
+
+pub(crate) fn hit_area_to_value(area: HitArea) -> u32 {
+    match area {
+        HitArea::Nowhere => HTNOWHERE,
+        HitArea::Client => HTCLIENT,
+        HitArea::Caption => HTCAPTION,
+        HitArea::MinimizeButton => HTMINBUTTON,
+        HitArea::MaximizeButton => HTMAXBUTTON,
+        HitArea::CloseButton => HTCLOSE,
+        HitArea::Left => HTLEFT,
+        HitArea::Top => HTTOP,
+        HitArea::Right => HTRIGHT,
+        HitArea::Bottom => HTBOTTOM,
+        HitArea::TopLeft => HTTOPLEFT,
+        HitArea::TopRight => HTTOPRIGHT,
+        HitArea::BottomLeft => HTBOTTOMLEFT,
+        HitArea::BottomRight => HTBOTTOMRIGHT,
+    }
+}
+
 unsafe fn wm_paint(hwnd: HWND) -> LRESULT {
     let context = context();
     let mut ps = PAINTSTRUCT::default();
@@ -91,10 +169,7 @@ unsafe fn wm_mouse_move(hwnd: HWND, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
         Some(window) => window,
         None => return DefWindowProcW(hwnd, WM_MOUSEMOVE, wparam, lparam),
     };
-    let state = MouseState {
-        position: lparam_to_point(lparam),
-        buttons: get_mouse_buttons(wparam),
-    };
+    let state = mouse_state(lparam_to_point(lparam), get_mouse_buttons(wparam));
     if context.entered_cursor_window.get().is_none() {
         TrackMouseEvent(&mut TRACKMOUSEEVENT {
             cbSize: std::mem::size_of::<TRACKMOUSEEVENT>() as _,
@@ -122,10 +197,10 @@ unsafe fn wm_mouse_leave(hwnd: HWND, wparam: WPARAM, lparam: LPARAM) -> LRESULT
     GetCursorPos(&mut position);
     ScreenToClient(hwnd, &mut position);
     context.entered_cursor_window.set(None);
-    window.cursor_leaved_channel.send(MouseState {
-        position: Physical(Point::new(position.x, position.y)),
-        buttons: get_mouse_buttons(wparam),
-    });
+    window.cursor_leaved_channel.send(mouse_state(
+        Physical(Point::new(position.x, position.y)),
+        get_mouse_buttons(wparam),
+    ));
     LRESULT(0)
 }
 
@@ -137,15 +212,27 @@ unsafe fn mouse_input(
     lparam: LPARAM,
 ) -> LRESULT {
     let context = context();
-    if let Some(window) = context.get_window(hwnd) {
-        match button_state {
-            ButtonState::Pressed => { SetCapture(hwnd); }
-            ButtonState::Released => { ReleaseCapture(); }
+    match context.get_window_mut(hwnd) {
+        Some(mut window) => match button_state {
+            ButtonState::Pressed => window.captured_button = Some(button),
+            ButtonState::Released => {
+                if window.captured_button == Some(button) {
+                    window.captured_button = None;
+                }
+            }
+        },
+        None => return LRESULT(0),
+    }
+    match button_state {
+        ButtonState::Pressed => {
+            SetCapture(hwnd);
         }
-        let mouse_state = MouseState {
-            position: lparam_to_point(lparam),
-            buttons: get_mouse_buttons(wparam),
-        };
+        ButtonState::Released => {
+            ReleaseCapture();
+        }
+    }
+    if let Some(window) = context.get_window(hwnd) {
+        let mouse_state = mouse_state(lparam_to_point(lparam), get_mouse_buttons(wparam));
         window.mouse_input_channel.send(event::MouseInput {
             button,
             button_state,
@@ -168,13 +255,13 @@ unsafe fn mouse_wheel(hwnd: HWND, msg: u32, wparam: WPARAM, lparam: LPARAM) -> L
         WM_MOUSEWHEEL => {
             window.mouse_wheel_channel.send(event::MouseWheel {
                 delta,
-                mouse_state: MouseState { buttons, position },
+                mouse_state: mouse_state(position, buttons),
             });
         }
         WM_MOUSEHWHEEL => {
             window.mouse_h_wheel_channel.send(event::MouseWheel {
                 delta,
-                mouse_state: MouseState { buttons, position },
+                mouse_state: mouse_state(position, buttons),
             });
         }
         _ => unreachable!(),
@@ -182,9 +269,57 @@ unsafe fn mouse_wheel(hwnd: HWND, msg: u32, wparam: WPARAM, lparam: LPARAM) -> L
     LRESULT(0)
 }
 
+const TO_UNICODE_NO_STATE_CHANGE: u32 = 0x04;
+
+unsafe fn to_unicode(
+    vkey: VirtualKeyCode,
+    scan_code: u32,
+    keyboard_state: &[u8; 256],
+    buffer: &mut [u16; 8],
+) -> i32 {
+    ToUnicodeEx(
+        vkey.0,
+        scan_code,
+        keyboard_state.as_ptr(),
+        PWSTR(buffer.as_mut_ptr()),
+        buffer.len() as _,
+        TO_UNICODE_NO_STATE_CHANGE,
+        GetKeyboardLayout(0),
+    )
+}
+
+unsafe fn translate_key(
+    vkey: VirtualKeyCode,
+    scan_code: u32,
+    state: ButtonState,
+) -> (Key, Option<String>) {
+    let scan_code = match state {
+        ButtonState::Pressed => scan_code,
+        ButtonState::Released => scan_code | 0x8000,
+    };
+    let mut keyboard_state = [0u8; 256];
+    GetKeyboardState(keyboard_state.as_mut_ptr());
+    let mut buffer = [0u16; 8];
+    let mut len = to_unicode(vkey, scan_code, &keyboard_state, &mut buffer);
+    let text = match state {
+        ButtonState::Pressed => text_from_utf16(len, &buffer),
+        ButtonState::Released => None,
+    };
+    let ctrl = keyboard_state[VK_CONTROL as usize] & 0x80 != 0;
+    let alt = keyboard_state[VK_MENU as usize] & 0x80 != 0;
+    if ctrl && !alt {
+        keyboard_state[VK_CONTROL as usize] = 0;
+        keyboard_state[VK_LCONTROL as usize] = 0;
+        keyboard_state[VK_RCONTROL as usize] = 0;
+        buffer = [0; 8];
+        len = to_unicode(vkey, scan_code, &keyboard_state, &mut buffer);
+    }
+    (Key::from_utf16(vkey, len, &buffer), text)
+}
+
 unsafe fn key_input(hwnd: HWND, msg: u32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
     let context = context();
-    let window = match context.get_window(hwnd) {
+    let mut window = match context.get_window_mut(hwnd) {
         Some(window) => window,
         None => return DefWindowProcW(hwnd, msg, wparam, lparam),
     };
@@ -216,26 +351,82 @@ unsafe fn key_input(hwnd: HWND, msg: u32, wparam: WPARAM, lparam: LPARAM) -> LRE
     let key_code = KeyCode {
         vkey: VirtualKeyCode(vkey),
         scan_code,
+        extended,
+        physical_key: PhysicalKey::from_scan_code(scan_code, extended),
     };
+    let (key, text) = translate_key(VirtualKeyCode(wparam.0 as _), scan_code, state);
     let prev_state = if (lparam.0 >> 30) & 0x01 != 0 {
         ButtonState::Pressed
     } else {
         ButtonState::Released
     };
+    let repeat = state == ButtonState::Pressed && prev_state == ButtonState::Pressed;
+    if repeat && !window.key_repeat {
+        return LRESULT(0);
+    }
+    let modifiers = current_modifiers();
+    if state == ButtonState::Pressed {
+        if let Some(id) = window.accelerators.process(key_code.vkey, modifiers) {
+            window.accelerator_channel.send(id);
+        }
+    }
     window.key_input_channel.send(event::KeyInput {
         state,
         key_code,
+        key,
+        text,
         prev_state,
+        repeat,
+        repeat_count: (lparam.0 & 0xffff) as u16,
+        modifiers,
+        lock_state: current_lock_state(),
     });
     LRESULT(0)
 }
 
+const WM_UNICHAR: u32 = 0x0109;
+
+unsafe fn has_pending_char(hwnd: HWND) -> bool {
+    let mut msg = MSG::default();
+    PeekMessageW(&mut msg, hwnd, WM_CHAR, WM_CHAR, PM_NOREMOVE).as_bool()
+}
+
 unsafe fn wm_char(hwnd: HWND, wparam: WPARAM) -> LRESULT {
+    let pending = has_pending_char(hwnd);
     let context = context();
-    if let Some(window) = context.get_window(hwnd) {
+    if let Some(mut window) = context.get_window_mut(hwnd) {
+        let chars = window
+            .text_input
+            .push_utf16(wparam.0 as _)
+            .collect::<Vec<_>>();
+        for c in chars {
+            window.char_input_channel.send(c);
+        }
+        if !pending {
+            if let Some(text) = window.text_input.take() {
+                window.text_input_channel.send(text);
+            }
+        }
+    }
+    LRESULT(0)
+}
+
+unsafe fn wm_unichar(hwnd: HWND, wparam: WPARAM) -> LRESULT {
+    if wparam.0 as u32 == UNICODE_NOCHAR {
+        return LRESULT(1);
+    }
+    let pending = has_pending_char(hwnd);
+    let context = context();
+    if let Some(mut window) = context.get_window_mut(hwnd) {
         if let Some(c) = char::from_u32(wparam.0 as _) {
+            let c = window.text_input.push_char(c);
             window.char_input_channel.send(c);
         }
+        if !pending {
+            if let Some(text) = window.text_input.take() {
+                window.text_input_channel.send(text);
+            }
+        }
     }
     LRESULT(0)
 }
@@ -381,6 +572,23 @@ unsafe fn wm_exit_size_move(hwnd: HWND, wparam: WPARAM, lparam: LPARAM) -> LRESU
     DefWindowProcW(hwnd, WM_EXITSIZEMOVE, wparam, lparam)
 }
 
+unsafe fn wm_display_change(hwnd: HWND, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    context().update_monitors();
+    DefWindowProcW(hwnd, WM_DISPLAYCHANGE, wparam, lparam)
+}
+
+unsafe fn wm_theme_changed(hwnd: HWND, msg: u32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    let context = context();
+    let theme = system::theme();
+    if let Some(mut window) = context.get_window_mut(hwnd) {
+        if window.theme != theme {
+            window.theme = theme;
+            window.theme_changed_channel.send(theme);
+        }
+    }
+    DefWindowProcW(hwnd, msg, wparam, lparam)
+}
+
 unsafe fn wm_dpi_changed(hwnd: HWND, lparam: LPARAM) -> LRESULT {
     let context = context();
     let rc = *(lparam.0 as *const RECT);
@@ -394,6 +602,18 @@ unsafe fn wm_dpi_changed(hwnd: HWND, lparam: LPARAM) -> LRESULT {
         SWP_NOZORDER | SWP_NOACTIVATE,
     );
     let dpi = GetDpiForWindow(hwnd);
+    let shape = context
+        .get_window_mut(hwnd)
+        .and_then(|mut window| window.shape.take());
+    if let Some(mut shape) = shape {
+        shape.current = shape
+            .source
+            .scale(ScaleFactor(dpi as f64 / shape.dpi as f64));
+        utility::set_window_shape(hwnd, Some(&shape.current));
+        if let Some(mut window) = context.get_window_mut(hwnd) {
+            window.shape = Some(shape);
+        }
+    }
     if let Some(window) = context.get_window(hwnd) {
         window.dpi_changed_channel.send(dpi);
     }
@@ -421,6 +641,21 @@ unsafe fn wm_get_dpi_scaled_size(hwnd: HWND, wparam: WPARAM, lparam: LPARAM) ->
     LRESULT(1)
 }
 
+unsafe fn wm_window_pos_changing(hwnd: HWND, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    let context = context();
+    let pos = &*(lparam.0 as *const WINDOWPOS);
+    if pos.flags & SWP_HIDEWINDOW != 0 {
+        let modal = context
+            .get_window_mut(hwnd)
+            .and_then(|mut window| window.modal.take());
+        if let Some(modal) = modal {
+            EnableWindow(modal.owner, true);
+            modal.ended.send(()).ok();
+        }
+    }
+    DefWindowProcW(hwnd, WM_WINDOWPOSCHANGING, wparam, lparam)
+}
+
 unsafe fn wm_activate(hwnd: HWND, wparam: WPARAM) -> LRESULT {
     let context = context();
     if let Some(window) = context.get_window(hwnd) {
@@ -486,6 +721,13 @@ unsafe fn wm_close(hwnd: HWND) -> LRESULT {
 
 unsafe fn wm_destroy(hwnd: HWND) -> LRESULT {
     let context = context();
+    let modal = context
+        .get_window_mut(hwnd)
+        .and_then(|mut window| window.modal.take());
+    if let Some(modal) = modal {
+        EnableWindow(modal.owner, true);
+        modal.ended.send(()).ok();
+    }
     if let Some(window) = context.get_window(hwnd) {
         window.closed_channel.send(());
     }
@@ -496,6 +738,91 @@ unsafe fn wm_destroy(hwnd: HWND) -> LRESULT {
     LRESULT(0)
 }
 
+unsafe fn wm_nc_hit_test(hwnd: HWND, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    let context = context();
+    let window = match context.get_window(hwnd) {
+        Some(window) => window,
+        None => return DefWindowProcW(hwnd, WM_NCHITTEST, wparam, lparam),
+    };
+    if window.hit_test.is_none() && window.shape.is_none() {
+        return DefWindowProcW(hwnd, WM_NCHITTEST, wparam, lparam);
+    }
+    let mut pt = POINT {
+        x: get_x_lparam(lparam) as _,
+        y: get_y_lparam(lparam) as _,
+    };
+    ScreenToClient(hwnd, &mut pt);
+    if let Some(shape) = window.shape.as_ref() {
+        if !shape.current.contains(&Physical(Point::new(pt.x, pt.y))) {
+            return LRESULT(HTNOWHERE as _);
+        }
+    }
+    let hit_test = match window.hit_test.as_ref() {
+        Some(hit_test) => hit_test,
+        None => return DefWindowProcW(hwnd, WM_NCHITTEST, wparam, lparam),
+    };
+    let mut rc = RECT::default();
+    GetClientRect(hwnd, &mut rc);
+    let area = hit_test.hit_test(
+        Physical(Point::new(pt.x, pt.y)),
+        Physical(Size::new(rc.right as _, rc.bottom as _)),
+        IsZoomed(hwnd).as_bool(),
+    );
+    LRESULT(hit_area_to_value(area) as _)
+}
+
+unsafe fn nc_button_input(hwnd: HWND, msg: u32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    let context = context();
+    let has_hit_test = context
+        .get_window(hwnd)
+        .is_some_and(|window| window.hit_test.is_some());
+    if !has_hit_test {
+        return DefWindowProcW(hwnd, msg, wparam, lparam);
+    }
+    let command = match wparam.0 as u32 {
+        HTMINBUTTON => SC_MINIMIZE,
+        HTMAXBUTTON => {
+            if IsZoomed(hwnd).as_bool() {
+                SC_RESTORE
+            } else {
+                SC_MAXIMIZE
+            }
+        }
+        HTCLOSE => SC_CLOSE,
+        _ => return DefWindowProcW(hwnd, msg, wparam, lparam),
+    };
+    if msg == WM_NCLBUTTONUP {
+        PostMessageW(hwnd, WM_SYSCOMMAND, WPARAM(command as _), LPARAM(0));
+    }
+    LRESULT(0)
+}
+
+unsafe fn wm_awita_drag(hwnd: HWND, wparam: WPARAM) -> LRESULT {
+    let context = context();
+    let button = context
+        .get_window_mut(hwnd)
+        .and_then(|mut window| window.captured_button.take());
+    ReleaseCapture();
+    let mut pt = POINT::default();
+    GetCursorPos(&mut pt);
+    let lparam = LPARAM(((pt.x & 0xffff) | ((pt.y & 0xffff) << 16)) as _);
+    DefWindowProcW(hwnd, WM_NCLBUTTONDOWN, wparam, lparam);
+    let button = match button {
+        Some(button) => button,
+        None => return LRESULT(0),
+    };
+    if let Some(window) = context.get_window(hwnd) {
+        GetCursorPos(&mut pt);
+        ScreenToClient(hwnd, &mut pt);
+        window.mouse_input_channel.send(event::MouseInput {
+            button,
+            button_state: ButtonState::Released,
+            mouse_state: mouse_state(Physical(Point::new(pt.x, pt.y)), current_mouse_buttons()),
+        });
+    }
+    LRESULT(0)
+}
+
 unsafe fn wm_nc_create(hwnd: HWND, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
     EnableNonClientDpiScaling(hwnd);
     DefWindowProcW(hwnd, WM_NCCREATE, wparam, lparam)
@@ -572,8 +899,9 @@ pub(crate) unsafe extern "system" fn window_proc(
         WM_KEYDOWN => key_input(hwnd, WM_KEYDOWN, wparam, lparam),
         WM_KEYUP => key_input(hwnd, WM_KEYUP, wparam, lparam),
         WM_SYSKEYDOWN => key_input(hwnd, WM_SYSKEYDOWN, wparam, lparam),
-        WM_SYSKEYUP => key_input(hwnd, WM_SYSKEYDOWN, wparam, lparam),
+        WM_SYSKEYUP => key_input(hwnd, WM_SYSKEYUP, wparam, lparam),
         WM_CHAR => wm_char(hwnd, wparam),
+        WM_UNICHAR => wm_unichar(hwnd, wparam),
         WM_IME_SETCONTEXT => wm_ime_set_context(hwnd, wparam, lparam),
         WM_IME_STARTCOMPOSITION => wm_ime_start_composition(hwnd, wparam, lparam),
         WM_IME_COMPOSITION => wm_ime_composition(hwnd, wparam, lparam),
@@ -583,12 +911,21 @@ pub(crate) unsafe extern "system" fn window_proc(
         WM_ENTERSIZEMOVE => wm_enter_size_move(hwnd, wparam, lparam),
         WM_EXITSIZEMOVE => wm_exit_size_move(hwnd, wparam, lparam),
         WM_DPICHANGED => wm_dpi_changed(hwnd, lparam),
+        WM_DISPLAYCHANGE => wm_display_change(hwnd, wparam, lparam),
+        WM_SETTINGCHANGE | WM_THEMECHANGED | WM_DWMCOLORIZATIONCOLORCHANGED => {
+            wm_theme_changed(hwnd, msg, wparam, lparam)
+        }
         WM_GETDPISCALEDSIZE => wm_get_dpi_scaled_size(hwnd, wparam, lparam),
+        WM_WINDOWPOSCHANGING => wm_window_pos_changing(hwnd, wparam, lparam),
         WM_ACTIVATE => wm_activate(hwnd, wparam),
         WM_DROPFILES => wm_drop_files(hwnd, wparam),
         WM_CLOSE => wm_close(hwnd),
         WM_DESTROY => wm_destroy(hwnd),
+        WM_NCHITTEST => wm_nc_hit_test(hwnd, wparam, lparam),
+        WM_NCLBUTTONDOWN => nc_button_input(hwnd, WM_NCLBUTTONDOWN, wparam, lparam),
+        WM_NCLBUTTONUP => nc_button_input(hwnd, WM_NCLBUTTONUP, wparam, lparam),
         WM_NCCREATE => wm_nc_create(hwnd, wparam, lparam),
+        WM_AWITA_DRAG => wm_awita_drag(hwnd, wparam),
         _ => DefWindowProcW(hwnd, msg, wparam, lparam),
     });
     match ret {
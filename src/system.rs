@@ -0,0 +1,98 @@
+use super::*;
+use windows::Win32::{
+    Foundation::*,
+    Graphics::Dwm::*,
+    System::Registry::*,
+    UI::{Accessibility::*, WindowsAndMessaging::*},
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColorMode {
+    Light,
+    Dark,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Theme {
+    pub color_mode: ColorMode,
+    pub accent_color: Option<Rgba<u8>>,
+    pub high_contrast: bool,
+    pub text_scale_factor: f64,
+}
+
+fn read_dword(subkey: &str, value: &str) -> Option<u32> {
+    unsafe {
+        let mut data = 0u32;
+        let mut len = std::mem::size_of::<u32>() as u32;
+        let ret = RegGetValueW(
+            HKEY_CURRENT_USER,
+            subkey,
+            value,
+            RRF_RT_REG_DWORD,
+            std::ptr::null_mut(),
+            &mut data as *mut _ as _,
+            &mut len,
+        );
+        (ret == ERROR_SUCCESS).then_some(data)
+    }
+}
+
+fn color_mode() -> ColorMode {
+    match read_dword(
+        "Software\\Microsoft\\Windows\\CurrentVersion\\Themes\\Personalize",
+        "AppsUseLightTheme",
+    ) {
+        Some(0) => ColorMode::Dark,
+        _ => ColorMode::Light,
+    }
+}
+
+fn accent_color() -> Option<Rgba<u8>> {
+    if let Some(abgr) = read_dword("Software\\Microsoft\\Windows\\DWM", "AccentColor") {
+        return Some(Rgba::new(
+            (abgr & 0xff) as u8,
+            ((abgr >> 8) & 0xff) as u8,
+            ((abgr >> 16) & 0xff) as u8,
+            0xff,
+        ));
+    }
+    unsafe {
+        let mut argb = 0u32;
+        let mut opaque = BOOL::default();
+        DwmGetColorizationColor(&mut argb, &mut opaque).ok()?;
+        Some(Rgba::new(
+            ((argb >> 16) & 0xff) as u8,
+            ((argb >> 8) & 0xff) as u8,
+            (argb & 0xff) as u8,
+            0xff,
+        ))
+    }
+}
+
+fn high_contrast() -> bool {
+    unsafe {
+        let mut hc = HIGHCONTRASTW {
+            cbSize: std::mem::size_of::<HIGHCONTRASTW>() as _,
+            ..Default::default()
+        };
+        SystemParametersInfoW(SPI_GETHIGHCONTRAST, hc.cbSize, &mut hc as *mut _ as _, 0).as_bool()
+            && hc.dwFlags & HCF_HIGHCONTRASTON != 0
+    }
+}
+
+fn text_scale_factor() -> f64 {
+    read_dword("Software\\Microsoft\\Accessibility", "TextScaleFactor")
+        .filter(|value| *value >= 100)
+        .map_or(1.0, |value| value as f64 / 100.0)
+}
+
+pub fn theme() -> Theme {
+    Theme {
+        color_mode: color_mode(),
+        accent_color: accent_color(),
+        high_contrast: high_contrast(),
+        text_scale_factor: text_scale_factor(),
+    }
+}
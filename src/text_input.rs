@@ -0,0 +1,108 @@
+use super::*;
+
+#[derive(Default, Debug)]
+pub(crate) struct TextInput {
+    high_surrogate: Option<u16>,
+    pending: String,
+}
+
+impl TextInput {
+    pub fn push_utf16(&mut self, unit: u16) -> impl Iterator<Item = char> {
+        let chars = match (self.high_surrogate.take(), unit) {
+            (None, 0xd800..=0xdbff) => {
+                self.high_surrogate = Some(unit);
+                [None, None]
+            }
+            (None, 0xdc00..=0xdfff) => [Some(char::REPLACEMENT_CHARACTER), None],
+            (None, _) => [char::from_u32(unit as u32), None],
+            (Some(high), 0xdc00..=0xdfff) => {
+                let c = 0x10000 + (((high as u32) - 0xd800) << 10) + ((unit as u32) - 0xdc00);
+                [char::from_u32(c), None]
+            }
+            (Some(_), 0xd800..=0xdbff) => {
+                self.high_surrogate = Some(unit);
+                [Some(char::REPLACEMENT_CHARACTER), None]
+            }
+            (Some(_), _) => [
+                Some(char::REPLACEMENT_CHARACTER),
+                char::from_u32(unit as u32),
+            ],
+        };
+        for c in chars.iter().flatten() {
+            self.push_text(*c);
+        }
+        chars.into_iter().flatten()
+    }
+
+    pub fn push_char(&mut self, c: char) -> char {
+        self.push_text(c);
+        c
+    }
+
+    pub fn take(&mut self) -> Option<String> {
+        (!self.pending.is_empty()).then(|| std::mem::take(&mut self.pending))
+    }
+
+    fn push_text(&mut self, c: char) {
+        if is_text_char(c) {
+            self.pending.push(c);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn push_all(input: &mut TextInput, units: &[u16]) -> Vec<char> {
+        units.iter().flat_map(|&u| input.push_utf16(u)).collect()
+    }
+
+    #[test]
+    fn bmp() {
+        let mut input = TextInput::default();
+        assert!(push_all(&mut input, &[0x61, 0xe9]) == ['a', 'é']);
+        assert!(input.take() == Some("aé".into()));
+        assert!(input.take() == None);
+    }
+
+    #[test]
+    fn surrogate_pair() {
+        let mut input = TextInput::default();
+        let units = "😀".encode_utf16().collect::<Vec<_>>();
+        assert!(input.push_utf16(units[0]).next() == None);
+        assert!(input.take() == None);
+        assert!(input.push_utf16(units[1]).collect::<Vec<_>>() == ['😀']);
+        assert!(input.take() == Some("😀".into()));
+    }
+
+    #[test]
+    fn supplementary_plane_round_trip() {
+        let s = "a𝄞b😀𠀋\u{10ffff}";
+        let mut input = TextInput::default();
+        let units = s.encode_utf16().collect::<Vec<_>>();
+        assert!(push_all(&mut input, &units).into_iter().collect::<String>() == s);
+        assert!(input.take() == Some(s.into()));
+    }
+
+    #[test]
+    fn lone_surrogates() {
+        let mut input = TextInput::default();
+        assert!(push_all(&mut input, &[0xdc00]) == [char::REPLACEMENT_CHARACTER]);
+        assert!(push_all(&mut input, &[0xd800, 0x61]) == [char::REPLACEMENT_CHARACTER, 'a']);
+        assert!(
+            push_all(&mut input, &[0xd800, 0xd83d, 0xde00]) == [char::REPLACEMENT_CHARACTER, '😀']
+        );
+        assert!(input.take() == Some("\u{fffd}\u{fffd}a\u{fffd}😀".into()));
+    }
+
+    #[test]
+    fn control_characters_are_not_text() {
+        let mut input = TextInput::default();
+        assert!(push_all(&mut input, &[0x08, 0x1b]) == ['\u{8}', '\u{1b}']);
+        assert!(input.take() == None);
+        input.push_char('\r');
+        input.push_char('x');
+        assert!(input.take() == Some("\rx".into()));
+    }
+}
@@ -1,4 +1,5 @@
 use crate::window::WindowState;
+use crate::{event, hotkey, utility, Error, HotkeyHandle, KeyStroke, Monitor, Point, Screen};
 use once_cell::sync::OnceCell;
 use std::cell::{Cell, Ref, RefCell, RefMut};
 use std::collections::HashMap;
@@ -6,6 +7,7 @@ use std::rc::Rc;
 use tokio::sync::{mpsc, oneshot, watch, Mutex};
 use windows::Win32::{
     Foundation::*,
+    Graphics::Gdi::*,
     System::{Com::*, Threading::*},
     UI::{HiDpi::*, WindowsAndMessaging::*},
 };
@@ -42,6 +44,41 @@ impl UiThread {
         th.method_tx.send(Box::new(f)).ok();
     }
 
+    pub async fn monitors() -> Vec<Monitor> {
+        let (tx, rx) = oneshot::channel();
+        Self::post_with_context(move |ctx| {
+            tx.send(ctx.query_monitors()).ok();
+        });
+        rx.await.unwrap_or_default()
+    }
+
+    /// Replaces the monitors awita reports with a fixed layout, e.g. for tests.
+    /// `None` goes back to the real monitors.
+    pub fn set_virtual_monitors(monitors: Option<Vec<Monitor>>) {
+        Self::post_with_context(move |ctx| {
+            *ctx.virtual_monitors.borrow_mut() = monitors;
+            ctx.update_monitors();
+        });
+    }
+
+    pub async fn monitors_changed_receiver() -> event::Receiver<Vec<Monitor>> {
+        let (tx, rx) = oneshot::channel();
+        Self::post_with_context(move |ctx| {
+            tx.send(ctx.monitors_changed_channel.rx.activate_cloned())
+                .ok();
+        });
+        event::Receiver(rx.await.ok())
+    }
+
+    pub async fn register_hotkey(stroke: impl Into<KeyStroke>) -> Result<HotkeyHandle, Error> {
+        let stroke = stroke.into();
+        let (tx, rx) = oneshot::channel();
+        Self::post_with_context(move |ctx| {
+            tx.send(hotkey::register(ctx, stroke)).ok();
+        });
+        rx.await?
+    }
+
     pub fn is_running() -> bool {
         Self::get().finish_rx.borrow().is_none()
     }
@@ -67,6 +104,12 @@ pub(crate) struct Context {
     unwind: RefCell<Option<Box<dyn std::any::Any + Send>>>,
     pub(crate) resizing: Cell<bool>,
     pub(crate) entered_cursor_window: Cell<Option<HWND>>,
+    pub(crate) monitors: RefCell<Vec<Monitor>>,
+    pub(crate) monitors_changed_channel: event::Channel<Vec<Monitor>>,
+    virtual_monitors: RefCell<Option<Vec<Monitor>>>,
+    pub(crate) hotkeys: RefCell<HashMap<i32, (KeyStroke, event::Channel<KeyStroke>)>>,
+    pub(crate) next_hotkey_id: Cell<i32>,
+    pub(crate) hotkey_window: Cell<Option<HWND>>,
 }
 
 impl Context {
@@ -81,6 +124,12 @@ impl Context {
             unwind: RefCell::new(None),
             resizing: Cell::new(false),
             entered_cursor_window: Cell::new(None),
+            monitors: RefCell::new(utility::monitors()),
+            monitors_changed_channel: event::Channel::new(1),
+            virtual_monitors: RefCell::new(None),
+            hotkeys: RefCell::new(HashMap::new()),
+            next_hotkey_id: Cell::new(1),
+            hotkey_window: Cell::new(None),
         })
     }
 
@@ -126,6 +175,34 @@ impl Context {
         }
     }
 
+    pub fn query_monitors(&self) -> Vec<Monitor> {
+        match self.virtual_monitors.borrow().as_ref() {
+            Some(monitors) => monitors.clone(),
+            None => utility::monitors(),
+        }
+    }
+
+    pub fn update_monitors(&self) {
+        let monitors = self.query_monitors();
+        if *self.monitors.borrow() != monitors {
+            *self.monitors.borrow_mut() = monitors.clone();
+            self.monitors_changed_channel.send(monitors);
+        }
+    }
+
+    pub fn monitor_from_rect(&self, rc: &RECT) -> Option<Monitor> {
+        match self.virtual_monitors.borrow().as_ref() {
+            Some(monitors) => {
+                let center = Screen(Point::new(
+                    rc.left + (rc.right - rc.left) / 2,
+                    rc.top + (rc.bottom - rc.top) / 2,
+                ));
+                Monitor::nearest(monitors, &center).cloned()
+            }
+            None => utility::monitor(unsafe { MonitorFromRect(rc, MONITOR_DEFAULTTONEAREST) }),
+        }
+    }
+
     pub fn set_unwind(&self, e: Box<dyn std::any::Any + Send>) {
         *self.unwind.borrow_mut() = Some(e);
     }
@@ -180,3 +257,49 @@ fn run() -> UiThread {
         unwind_rx: Mutex::new(Some(unwind_rx)),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Rect, Window};
+
+    fn monitor(name: &str, x: i32, y: i32, w: i32, h: i32, primary: bool) -> Monitor {
+        Monitor {
+            name: name.into(),
+            rect: Screen(Rect::new((x, y), (w, h))),
+            work_area: Screen(Rect::new((x, y), (w, h - 40))),
+            dpi: 96,
+            refresh_rate: None,
+            primary,
+        }
+    }
+
+    #[test]
+    fn virtual_monitors() {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        rt.block_on(async {
+            let layout = vec![
+                monitor("VIRTUAL1", -20000, 0, 20000, 20000, false),
+                monitor("VIRTUAL2", 0, 0, 20000, 20000, true),
+            ];
+            let mut rx = UiThread::monitors_changed_receiver().await;
+            UiThread::set_virtual_monitors(Some(layout.clone()));
+            assert!(rx.recv().await.unwrap() == layout);
+            assert!(UiThread::monitors().await == layout);
+            let window = Window::builder()
+                .position(Screen(Point::new(100, 100)))
+                .build()
+                .await
+                .unwrap();
+            assert!(window.current_monitor().await.unwrap().name == "VIRTUAL2");
+            let placement = window.placement().await.unwrap();
+            assert!(placement.monitor.as_deref() == Some("VIRTUAL2"));
+            assert!(placement.monitor_rect == Some(layout[1].rect));
+            UiThread::set_virtual_monitors(None);
+            assert!(UiThread::monitors().await != layout);
+            window.close();
+        });
+    }
+}
@@ -1,7 +1,8 @@
 use super::*;
 use windows::Win32::{
     Foundation::*,
-    UI::{HiDpi::*, WindowsAndMessaging::*},
+    Graphics::{Dwm::*, Gdi::*},
+    UI::{HiDpi::*, Input::KeyboardAndMouse::*, WindowsAndMessaging::*},
 };
 
 pub fn adjust_window_size(
@@ -24,3 +25,200 @@ pub fn adjust_window_size(
         ))
     }
 }
+
+pub fn monitor_info(hmonitor: HMONITOR) -> Option<MONITORINFOEXW> {
+    unsafe {
+        let mut info = MONITORINFOEXW::default();
+        info.monitorInfo.cbSize = std::mem::size_of::<MONITORINFOEXW>() as _;
+        GetMonitorInfoW(hmonitor, &mut info as *mut _ as *mut MONITORINFO)
+            .as_bool()
+            .then_some(info)
+    }
+}
+
+pub fn monitor_name(info: &MONITORINFOEXW) -> String {
+    let len = info
+        .szDevice
+        .iter()
+        .position(|c| *c == 0)
+        .unwrap_or(info.szDevice.len());
+    String::from_utf16_lossy(&info.szDevice[..len])
+}
+
+pub fn monitor_dpi(hmonitor: HMONITOR) -> u32 {
+    unsafe {
+        let mut dpi_x = 0;
+        let mut _dpi_y = 0;
+        GetDpiForMonitor(hmonitor, MDT_DEFAULT, &mut dpi_x, &mut _dpi_y)
+            .map(|_| dpi_x)
+            .unwrap_or(DEFAULT_DPI as _)
+    }
+}
+
+fn refresh_rate(info: &MONITORINFOEXW) -> Option<u32> {
+    unsafe {
+        let mut mode = DEVMODEW {
+            dmSize: std::mem::size_of::<DEVMODEW>() as _,
+            ..Default::default()
+        };
+        EnumDisplaySettingsW(
+            PWSTR(info.szDevice.as_ptr() as _),
+            ENUM_CURRENT_SETTINGS,
+            &mut mode,
+        )
+        .as_bool()
+        .then_some(mode.dmDisplayFrequency)
+        .filter(|rate| *rate > 1)
+    }
+}
+
+fn to_screen_rect(rc: &RECT) -> ScreenRect<i32> {
+    Screen(Rect::new(
+        (rc.left, rc.top),
+        (rc.right - rc.left, rc.bottom - rc.top),
+    ))
+}
+
+pub fn monitor(hmonitor: HMONITOR) -> Option<Monitor> {
+    let info = monitor_info(hmonitor)?;
+    Some(Monitor {
+        name: monitor_name(&info),
+        rect: to_screen_rect(&info.monitorInfo.rcMonitor),
+        work_area: to_screen_rect(&info.monitorInfo.rcWork),
+        dpi: monitor_dpi(hmonitor),
+        refresh_rate: refresh_rate(&info),
+        primary: info.monitorInfo.dwFlags & MONITORINFOF_PRIMARY != 0,
+    })
+}
+
+unsafe extern "system" fn enum_monitors_proc(
+    hmonitor: HMONITOR,
+    _hdc: HDC,
+    _rc: *mut RECT,
+    lparam: LPARAM,
+) -> BOOL {
+    let monitors = &mut *(lparam.0 as *mut Vec<HMONITOR>);
+    monitors.push(hmonitor);
+    true.into()
+}
+
+pub fn enum_monitors() -> Vec<HMONITOR> {
+    let mut monitors: Vec<HMONITOR> = vec![];
+    unsafe {
+        EnumDisplayMonitors(
+            HDC::default(),
+            std::ptr::null(),
+            Some(enum_monitors_proc),
+            LPARAM(&mut monitors as *mut _ as _),
+        );
+    }
+    monitors
+}
+
+pub fn primary_monitor() -> HMONITOR {
+    unsafe { MonitorFromPoint(POINT { x: 0, y: 0 }, MONITOR_DEFAULTTOPRIMARY) }
+}
+
+pub fn find_monitor(name: &str) -> Option<HMONITOR> {
+    enum_monitors()
+        .into_iter()
+        .find(|hmonitor| monitor_info(*hmonitor).is_some_and(|info| monitor_name(&info) == name))
+}
+
+pub fn work_area(hmonitor: HMONITOR) -> (ScreenPoint<i32>, PhysicalSize<u32>) {
+    let rc = monitor_info(hmonitor)
+        .map(|info| info.monitorInfo.rcWork)
+        .unwrap_or_default();
+    (
+        Screen(Point::new(rc.left, rc.top)),
+        Physical(Size::new(
+            (rc.right - rc.left) as _,
+            (rc.bottom - rc.top) as _,
+        )),
+    )
+}
+
+pub fn monitors() -> Vec<Monitor> {
+    enum_monitors().into_iter().filter_map(monitor).collect()
+}
+
+pub fn frame_rect(hwnd: HWND) -> RECT {
+    unsafe {
+        let mut rc = RECT::default();
+        let ret = DwmGetWindowAttribute(
+            hwnd,
+            DWMWA_EXTENDED_FRAME_BOUNDS,
+            &mut rc as *mut RECT as _,
+            std::mem::size_of::<RECT>() as _,
+        );
+        if ret.is_err() {
+            GetWindowRect(hwnd, &mut rc);
+        }
+        rc
+    }
+}
+
+pub fn set_window_shape(hwnd: HWND, region: Option<&Region>) {
+    unsafe {
+        let region = match region {
+            Some(region) => region,
+            None => {
+                SetWindowRgn(hwnd, HRGN::default(), true);
+                return;
+            }
+        };
+        let mut rc = RECT::default();
+        GetWindowRect(hwnd, &mut rc);
+        let mut origin = POINT::default();
+        ClientToScreen(hwnd, &mut origin);
+        let dx = origin.x - rc.left;
+        let dy = origin.y - rc.top;
+        let hrgn = CreateRectRgn(0, 0, 0, 0);
+        for rc in region.iter() {
+            let part = CreateRectRgn(
+                rc.left() + dx,
+                rc.top() + dy,
+                rc.right() + dx,
+                rc.bottom() + dy,
+            );
+            CombineRgn(hrgn, hrgn, part, RGN_OR);
+            DeleteObject(part);
+        }
+        if SetWindowRgn(hwnd, hrgn, true) == 0 {
+            DeleteObject(hrgn);
+        }
+    }
+}
+
+pub fn key_name(key: VirtualKey) -> Option<String> {
+    unsafe {
+        let scan_code = MapVirtualKeyW(key as u32, MAPVK_VK_TO_VSC);
+        if scan_code == 0 {
+            return None;
+        }
+        let extended = matches!(
+            key,
+            VirtualKey::Insert
+                | VirtualKey::Delete
+                | VirtualKey::Home
+                | VirtualKey::End
+                | VirtualKey::PageUp
+                | VirtualKey::PageDown
+                | VirtualKey::Left
+                | VirtualKey::Up
+                | VirtualKey::Right
+                | VirtualKey::Down
+                | VirtualKey::NumDiv
+                | VirtualKey::NumLock
+                | VirtualKey::RCtrl
+                | VirtualKey::RAlt
+                | VirtualKey::LWin
+                | VirtualKey::RWin
+                | VirtualKey::Apps
+        );
+        let lparam = (scan_code << 16) | ((extended as u32) << 24);
+        let mut buffer = [0u16; 64];
+        let len = GetKeyNameTextW(lparam as _, PWSTR(buffer.as_mut_ptr()), buffer.len() as _);
+        (len > 0).then(|| String::from_utf16_lossy(&buffer[..len as usize]))
+    }
+}
@@ -6,7 +6,7 @@ use windows::{
         Foundation::*,
         Graphics::Gdi::*,
         System::LibraryLoader::GetModuleHandleW,
-        UI::{HiDpi::*, Shell::*, WindowsAndMessaging::*},
+        UI::{HiDpi::*, Input::KeyboardAndMouse::*, Shell::*, WindowsAndMessaging::*},
     },
 };
 
@@ -113,9 +113,48 @@ impl StyleObject for Style {
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FrameExtents {
+    pub left: u32,
+    pub top: u32,
+    pub right: u32,
+    pub bottom: u32,
+}
+
+#[derive(Clone, Debug)]
+pub enum Position {
+    Default,
+    Screen(ScreenPoint<i32>),
+    Logical(LogicalPoint<i32>),
+    Centered,
+    CenteredOn(Window),
+    OnMonitor(String, LogicalPoint<i32>),
+}
+
+impl From<ScreenPoint<i32>> for Position {
+    #[inline]
+    fn from(src: ScreenPoint<i32>) -> Self {
+        Self::Screen(src)
+    }
+}
+
+impl From<LogicalPoint<i32>> for Position {
+    #[inline]
+    fn from(src: LogicalPoint<i32>) -> Self {
+        Self::Logical(src)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Relation {
+    Owner(HWND),
+    Parent(HWND),
+}
+
 pub struct Builder {
     title: String,
-    position: ScreenPoint<i32>,
+    position: Position,
     size: Box<dyn ToPhysical<Value = u32, Output = Size<u32>> + Send>,
     visibility: bool,
     icon: Option<Icon>,
@@ -124,7 +163,13 @@ pub struct Builder {
     ime_composition_window_visibility: bool,
     ime_candidate_window_visibility: bool,
     accept_drop_files: bool,
+    key_repeat: bool,
+    accelerators: AcceleratorTable,
     style: Style,
+    relation: Option<Relation>,
+    hit_test: Option<Box<dyn HitTest + Send>>,
+    placement: Option<WindowPlacement>,
+    appearance: Appearance,
 }
 
 impl Builder {
@@ -132,7 +177,7 @@ impl Builder {
     pub fn new() -> Self {
         Self {
             title: "".into(),
-            position: Screen(Point::new(0, 0)),
+            position: Position::Default,
             size: Box::new(Logical(Size::new(640, 480))),
             visibility: true,
             icon: None,
@@ -141,7 +186,13 @@ impl Builder {
             ime_composition_window_visibility: true,
             ime_candidate_window_visibility: true,
             accept_drop_files: false,
+            key_repeat: true,
+            accelerators: AcceleratorTable::new(),
             style: Style::new(),
+            relation: None,
+            hit_test: None,
+            placement: None,
+            appearance: Appearance::default(),
         }
     }
 
@@ -152,8 +203,8 @@ impl Builder {
     }
 
     #[inline]
-    pub fn position(mut self, position: ScreenPoint<i32>) -> Self {
-        self.position = position;
+    pub fn position(mut self, position: impl Into<Position>) -> Self {
+        self.position = position.into();
         self
     }
 
@@ -208,6 +259,18 @@ impl Builder {
         self
     }
 
+    #[inline]
+    pub fn key_repeat(mut self, flag: bool) -> Self {
+        self.key_repeat = flag;
+        self
+    }
+
+    #[inline]
+    pub fn accelerators(mut self, accelerators: AcceleratorTable) -> Self {
+        self.accelerators = accelerators;
+        self
+    }
+
     #[inline]
     pub fn style(mut self, object: impl StyleObject) -> Self {
         self.style = Style {
@@ -217,6 +280,75 @@ impl Builder {
         self
     }
 
+    #[inline]
+    pub fn placement(mut self, placement: WindowPlacement) -> Self {
+        self.placement = Some(placement);
+        self
+    }
+
+    #[inline]
+    pub fn hit_test(mut self, hit_test: impl HitTest + Send + 'static) -> Self {
+        self.hit_test = Some(Box::new(hit_test));
+        self
+    }
+
+    #[inline]
+    pub fn dark_mode(mut self, flag: bool) -> Self {
+        self.appearance.dark_mode = Some(flag);
+        self
+    }
+
+    #[inline]
+    pub fn backdrop(mut self, backdrop: Backdrop) -> Self {
+        self.appearance.backdrop = Some(backdrop);
+        self
+    }
+
+    #[inline]
+    pub fn corner_preference(mut self, corner_preference: CornerPreference) -> Self {
+        self.appearance.corner_preference = Some(corner_preference);
+        self
+    }
+
+    #[inline]
+    pub fn caption_color(mut self, color: impl Into<FrameColor>) -> Self {
+        self.appearance.caption_color = Some(color.into());
+        self
+    }
+
+    #[inline]
+    pub fn border_color(mut self, color: impl Into<FrameColor>) -> Self {
+        self.appearance.border_color = Some(color.into());
+        self
+    }
+
+    #[inline]
+    pub fn text_color(mut self, color: impl Into<FrameColor>) -> Self {
+        self.appearance.text_color = Some(color.into());
+        self
+    }
+
+    #[inline]
+    pub fn owner(mut self, owner: &Window) -> Self {
+        self.relation = Some(Relation::Owner(owner.hwnd));
+        self
+    }
+
+    /// The window's position is converted into the parent's client area. `Position::Default` is
+    /// the client area's origin and `Position::Centered` centers the window in it.
+    #[inline]
+    pub fn parent(mut self, parent: &Window) -> Self {
+        self.relation = Some(Relation::Parent(parent.hwnd));
+        self
+    }
+
+    /// See `parent`.
+    #[inline]
+    pub fn parent_raw(mut self, parent: *mut std::ffi::c_void) -> Self {
+        self.relation = Some(Relation::Parent(HWND(parent as _)));
+        self
+    }
+
     #[inline]
     pub async fn build(self) -> Result<Window, Error> {
         Window::new(self).await
@@ -267,12 +399,142 @@ fn get_dpi_from_point(pt: ScreenPoint<i32>) -> u32 {
     }
 }
 
+fn centered(
+    area_position: ScreenPoint<i32>,
+    area_size: PhysicalSize<u32>,
+    size: PhysicalSize<u32>,
+) -> ScreenPoint<i32> {
+    let x = area_position.x as i64 + (area_size.width as i64 - size.width as i64) / 2;
+    let y = area_position.y as i64 + (area_size.height as i64 - size.height as i64) / 2;
+    Screen(Point::new(x as _, y as _))
+}
+
+fn logical_to_screen(pt: LogicalPoint<i32>) -> (ScreenPoint<i32>, u32) {
+    unsafe {
+        let hmonitor = MonitorFromPoint(POINT { x: pt.x, y: pt.y }, MONITOR_DEFAULTTONEAREST);
+        let dpi = utility::monitor_dpi(hmonitor);
+        let origin = utility::monitor_info(hmonitor)
+            .map(|info| info.monitorInfo.rcMonitor)
+            .unwrap_or_default();
+        let scale = |value: i32, origin: i32| {
+            origin as i64 + (value as i64 - origin as i64) * dpi as i64 / DEFAULT_DPI as i64
+        };
+        (
+            Screen(Point::new(
+                scale(pt.x, origin.left) as _,
+                scale(pt.y, origin.top) as _,
+            )),
+            dpi,
+        )
+    }
+}
+
+fn resolve_position(
+    position: &Position,
+    outer_size: impl Fn(u32) -> PhysicalSize<u32>,
+) -> (ScreenPoint<i32>, PhysicalSize<u32>, u32) {
+    match position {
+        Position::Default => {
+            let dpi = utility::monitor_dpi(utility::primary_monitor());
+            (
+                Screen(Point::new(CW_USEDEFAULT, CW_USEDEFAULT)),
+                outer_size(dpi),
+                dpi,
+            )
+        }
+        Position::Screen(pt) => {
+            let dpi = get_dpi_from_point(*pt);
+            (*pt, outer_size(dpi), dpi)
+        }
+        Position::Logical(pt) => {
+            let (pt, dpi) = logical_to_screen(*pt);
+            (pt, outer_size(dpi), dpi)
+        }
+        Position::Centered => {
+            let hmonitor = utility::primary_monitor();
+            let dpi = utility::monitor_dpi(hmonitor);
+            let size = outer_size(dpi);
+            let (area_position, area_size) = utility::work_area(hmonitor);
+            (centered(area_position, area_size, size), size, dpi)
+        }
+        Position::CenteredOn(window) => unsafe {
+            let mut rc = RECT::default();
+            if !GetWindowRect(window.hwnd, &mut rc).as_bool() {
+                return resolve_position(&Position::Centered, outer_size);
+            }
+            let dpi = GetDpiForWindow(window.hwnd);
+            let size = outer_size(dpi);
+            let area_position = Screen(Point::new(rc.left, rc.top));
+            let area_size = Physical(Size::new(
+                (rc.right - rc.left) as _,
+                (rc.bottom - rc.top) as _,
+            ));
+            (centered(area_position, area_size, size), size, dpi)
+        },
+        Position::OnMonitor(name, offset) => {
+            let hmonitor = utility::find_monitor(name).unwrap_or_else(utility::primary_monitor);
+            let dpi = utility::monitor_dpi(hmonitor);
+            let (area_position, _) = utility::work_area(hmonitor);
+            let offset = offset.to_physical(dpi as _);
+            (
+                Screen(Point::new(
+                    area_position.x + offset.x,
+                    area_position.y + offset.y,
+                )),
+                outer_size(dpi),
+                dpi,
+            )
+        }
+    }
+}
+
+fn resolve_child_position(
+    parent: HWND,
+    position: &Position,
+    size: PhysicalSize<u32>,
+) -> PhysicalPoint<i32> {
+    unsafe {
+        match position {
+            Position::Default => Physical(Point::new(0, 0)),
+            Position::Centered => {
+                let mut rc = RECT::default();
+                GetClientRect(parent, &mut rc);
+                let area_size = Physical(Size::new(rc.right as _, rc.bottom as _));
+                let pt = centered(Screen(Point::new(0, 0)), area_size, size);
+                Physical(Point::new(pt.x, pt.y))
+            }
+            _ => {
+                let (pt, _, _) = resolve_position(position, |_| size);
+                let mut pt = POINT { x: pt.x, y: pt.y };
+                ScreenToClient(parent, &mut pt);
+                Physical(Point::new(pt.x, pt.y))
+            }
+        }
+    }
+}
+
+pub(crate) struct Modal {
+    pub owner: HWND,
+    pub ended: oneshot::Sender<()>,
+}
+
+pub(crate) struct Shape {
+    pub source: Region,
+    pub dpi: u32,
+    pub current: Region,
+}
+
 pub(crate) struct WindowState {
     pub cursor: Option<Cursor>,
     pub ime_composition_window_visibility: bool,
     pub ime_candidate_window_visibility: bool,
     pub ime_context: ime::ImmContext,
     pub ime_position: PhysicalPoint<i32>,
+    pub captured_button: Option<MouseButton>,
+    pub key_repeat: bool,
+    pub accelerators: AcceleratorTable,
+    pub hit_test: Option<Box<dyn HitTest>>,
+    pub shape: Option<Shape>,
     pub draw_channel: event::Channel<()>,
     pub cursor_entered_channel: event::Channel<MouseState>,
     pub cursor_leaved_channel: event::Channel<MouseState>,
@@ -281,7 +543,10 @@ pub(crate) struct WindowState {
     pub mouse_wheel_channel: event::Channel<event::MouseWheel>,
     pub mouse_h_wheel_channel: event::Channel<event::MouseWheel>,
     pub key_input_channel: event::Channel<event::KeyInput>,
+    pub accelerator_channel: event::Channel<u32>,
     pub char_input_channel: event::Channel<char>,
+    pub text_input: TextInput,
+    pub text_input_channel: event::Channel<String>,
     pub ime_start_composition_channel: event::Channel<()>,
     pub ime_composition_channel: event::Channel<(ime::Composition, Option<ime::CandidateList>)>,
     pub ime_end_composition_channel: event::Channel<Option<String>>,
@@ -291,7 +556,11 @@ pub(crate) struct WindowState {
     pub activated_channel: event::Channel<()>,
     pub inactivated_channel: event::Channel<()>,
     pub dpi_changed_channel: event::Channel<u32>,
+    pub theme: system::Theme,
+    pub frame_extended: bool,
+    pub theme_changed_channel: event::Channel<system::Theme>,
     pub drop_files_channel: event::Channel<event::DropFiles>,
+    pub modal: Option<Modal>,
     pub close_request_channel: Option<mpsc::Sender<event::CloseRequest>>,
     pub closed_channel: event::Channel<()>,
 }
@@ -305,25 +574,54 @@ impl Window {
     async fn new(builder: Builder) -> Result<Self, Error> {
         let (tx, rx) = tokio::sync::oneshot::channel();
         UiThread::post_with_context(move |ctx| unsafe {
-            let dpi = get_dpi_from_point(builder.position);
+            let (style, parent) = match builder.relation {
+                Some(Relation::Parent(parent)) => {
+                    ((builder.style.value & !WS_POPUP) | WS_CHILD, parent)
+                }
+                Some(Relation::Owner(owner)) => (builder.style.value, owner),
+                None => (builder.style.value, HWND::default()),
+            };
             let title = builder
                 .title
                 .encode_utf16()
                 .chain(Some(0))
                 .collect::<Vec<_>>();
-            let size = builder.size.to_physical(dpi);
-            let size =
-                utility::adjust_window_size(size, WS_OVERLAPPEDWINDOW, 0, dpi);
+            let outer_size = |dpi: u32| {
+                let size = builder.size.to_physical(dpi);
+                utility::adjust_window_size(size, style, builder.style.ex, dpi)
+            };
+            let (position, size, dpi, show_cmd) = match builder.placement.as_ref() {
+                Some(placement) => {
+                    let placement = placement.fit(&ctx.query_monitors());
+                    let show_cmd = match placement.show_state {
+                        ShowState::Normal => SW_SHOW,
+                        ShowState::Minimized => SW_SHOWMINIMIZED,
+                        ShowState::Maximized => SW_SHOWMAXIMIZED,
+                    };
+                    let position = placement.position.0;
+                    (position, placement.size, placement.dpi, show_cmd)
+                }
+                None if matches!(builder.relation, Some(Relation::Parent(_))) => {
+                    let dpi = GetDpiForWindow(parent);
+                    let size = outer_size(dpi);
+                    let position = resolve_child_position(parent, &builder.position, size);
+                    (position.0, size, dpi, SW_SHOW)
+                }
+                None => {
+                    let (position, size, dpi) = resolve_position(&builder.position, outer_size);
+                    (position.0, size, dpi, SW_SHOW)
+                }
+            };
             let hwnd = CreateWindowExW(
                 builder.style.ex,
                 PWSTR(window_class().as_ptr() as _),
                 PWSTR(title.as_ptr() as _),
-                builder.style.value,
-                builder.position.x,
-                builder.position.y,
+                style,
+                position.x,
+                position.y,
                 size.width as _,
                 size.height as _,
-                None,
+                parent,
                 None,
                 GetModuleHandleW(None),
                 std::ptr::null_mut(),
@@ -332,6 +630,19 @@ impl Window {
                 tx.send(Err(windows::core::Error::from_win32().into())).ok();
                 return;
             }
+            let actual_dpi = GetDpiForWindow(hwnd);
+            if builder.placement.is_none() && actual_dpi != dpi {
+                let size = outer_size(actual_dpi);
+                SetWindowPos(
+                    hwnd,
+                    HWND::default(),
+                    0,
+                    0,
+                    size.width as _,
+                    size.height as _,
+                    SWP_NOZORDER | SWP_NOMOVE | SWP_NOACTIVATE,
+                );
+            }
             if let Some(icon) = builder.icon {
                 let big = LPARAM(icon.load().unwrap().0 as _);
                 SendMessageW(hwnd, WM_SETICON, WPARAM(ICON_BIG as _), big);
@@ -344,7 +655,14 @@ impl Window {
                 );
             }
             DragAcceptFiles(hwnd, builder.accept_drop_files);
-            ShowWindow(hwnd, SW_SHOW);
+            let mut frame_extended = false;
+            builder.appearance.apply(hwnd, &mut frame_extended);
+            let show_cmd = if builder.visibility {
+                show_cmd
+            } else {
+                SW_HIDE
+            };
+            ShowWindow(hwnd, show_cmd);
             ctx.insert_window(
                 hwnd,
                 WindowState {
@@ -353,6 +671,11 @@ impl Window {
                     ime_candidate_window_visibility: builder.ime_candidate_window_visibility,
                     ime_context: ime::ImmContext::new(hwnd),
                     ime_position: Physical(Point::new(0, 0)),
+                    captured_button: None,
+                    key_repeat: builder.key_repeat,
+                    accelerators: builder.accelerators,
+                    hit_test: builder.hit_test.map(|h| h as Box<dyn HitTest>),
+                    shape: None,
                     draw_channel: event::Channel::new(1),
                     cursor_entered_channel: event::Channel::new(1),
                     cursor_leaved_channel: event::Channel::new(1),
@@ -361,7 +684,10 @@ impl Window {
                     mouse_wheel_channel: event::Channel::new(1),
                     mouse_h_wheel_channel: event::Channel::new(1),
                     key_input_channel: event::Channel::new(8),
+                    accelerator_channel: event::Channel::new(8),
                     char_input_channel: event::Channel::new(8),
+                    text_input: TextInput::default(),
+                    text_input_channel: event::Channel::new(8),
                     ime_start_composition_channel: event::Channel::new(1),
                     ime_composition_channel: event::Channel::new(1),
                     ime_end_composition_channel: event::Channel::new(1),
@@ -371,7 +697,11 @@ impl Window {
                     activated_channel: event::Channel::new(1),
                     inactivated_channel: event::Channel::new(1),
                     dpi_changed_channel: event::Channel::new(1),
+                    theme: system::theme(),
+                    frame_extended,
+                    theme_changed_channel: event::Channel::new(1),
                     drop_files_channel: event::Channel::new(1),
+                    modal: None,
                     close_request_channel: None,
                     closed_channel: event::Channel::new(1),
                 },
@@ -426,18 +756,103 @@ impl Window {
         });
     }
 
+    /// Top-left corner of the visible frame, excluding the invisible resize borders.
     #[inline]
     pub async fn position(&self) -> Result<Screen<Point<i32>>, Error> {
         let hwnd = self.hwnd.clone();
         let (tx, rx) = oneshot::channel();
-        UiThread::post(move || unsafe {
-            let mut rc = RECT::default();
-            GetWindowRect(hwnd, &mut rc);
+        UiThread::post(move || {
+            let rc = utility::frame_rect(hwnd);
             tx.send(Screen(Point::new(rc.left, rc.top))).ok();
         });
         Ok(rx.await?)
     }
 
+    #[inline]
+    pub async fn inner_position(&self) -> Result<Screen<Point<i32>>, Error> {
+        self.client_to_screen(Physical(Point::new(0, 0))).await
+    }
+
+    /// Size of the visible frame, excluding the invisible resize borders.
+    #[inline]
+    pub async fn outer_size(&self) -> Result<Physical<Size<u32>>, Error> {
+        let hwnd = self.hwnd;
+        let (tx, rx) = oneshot::channel();
+        UiThread::post(move || {
+            let rc = utility::frame_rect(hwnd);
+            tx.send(Physical(Size::new(
+                (rc.right - rc.left) as _,
+                (rc.bottom - rc.top) as _,
+            )))
+            .ok();
+        });
+        Ok(rx.await?)
+    }
+
+    /// Distances from the client area to the edges of the visible frame.
+    #[inline]
+    pub async fn frame_extents(&self) -> Result<FrameExtents, Error> {
+        let hwnd = self.hwnd;
+        let (tx, rx) = oneshot::channel();
+        UiThread::post(move || unsafe {
+            let rc = utility::frame_rect(hwnd);
+            let mut client = RECT::default();
+            GetClientRect(hwnd, &mut client);
+            let mut origin = POINT::default();
+            ClientToScreen(hwnd, &mut origin);
+            tx.send(FrameExtents {
+                left: (origin.x - rc.left).max(0) as _,
+                top: (origin.y - rc.top).max(0) as _,
+                right: (rc.right - (origin.x + client.right)).max(0) as _,
+                bottom: (rc.bottom - (origin.y + client.bottom)).max(0) as _,
+            })
+            .ok();
+        });
+        Ok(rx.await?)
+    }
+
+    /// Converts a client-area point, as carried by `MouseState::position` and
+    /// `DropFiles::position`, to screen coordinates.
+    /// `moved_receiver` reports the client area's top-left corner in screen coordinates.
+    #[inline]
+    pub async fn client_to_screen(
+        &self,
+        position: PhysicalPoint<i32>,
+    ) -> Result<Screen<Point<i32>>, Error> {
+        let hwnd = self.hwnd;
+        let (tx, rx) = oneshot::channel();
+        UiThread::post(move || unsafe {
+            let mut pt = POINT {
+                x: position.x,
+                y: position.y,
+            };
+            ClientToScreen(hwnd, &mut pt);
+            tx.send(Screen(Point::new(pt.x, pt.y))).ok();
+        });
+        Ok(rx.await?)
+    }
+
+    /// Converts a screen point to the client-area space used by `MouseState::position` and
+    /// `DropFiles::position`.
+    #[inline]
+    pub async fn screen_to_client(
+        &self,
+        position: ScreenPoint<i32>,
+    ) -> Result<Physical<Point<i32>>, Error> {
+        let hwnd = self.hwnd;
+        let (tx, rx) = oneshot::channel();
+        UiThread::post(move || unsafe {
+            let mut pt = POINT {
+                x: position.x,
+                y: position.y,
+            };
+            ScreenToClient(hwnd, &mut pt);
+            tx.send(Physical(Point::new(pt.x, pt.y))).ok();
+        });
+        Ok(rx.await?)
+    }
+
+    /// Moves the top-left corner of the visible frame, matching `position`.
     #[inline]
     pub fn set_position<T>(&self, position: T)
     where
@@ -447,11 +862,14 @@ impl Window {
         UiThread::post(move || unsafe {
             let dpi = GetDpiForWindow(hwnd) as i32;
             let position = position.to_physical(dpi);
+            let frame = utility::frame_rect(hwnd);
+            let mut rc = RECT::default();
+            GetWindowRect(hwnd, &mut rc);
             SetWindowPos(
                 hwnd,
                 HWND::default(),
-                position.x,
-                position.y,
+                position.x - (frame.left - rc.left),
+                position.y - (frame.top - rc.top),
                 0,
                 0,
                 SWP_NOZORDER | SWP_NOSIZE | SWP_NOACTIVATE,
@@ -459,6 +877,53 @@ impl Window {
         });
     }
 
+    #[inline]
+    pub async fn placement(&self) -> Result<WindowPlacement, Error> {
+        let hwnd = self.hwnd;
+        let (tx, rx) = oneshot::channel();
+        UiThread::post_with_context(move |ctx| unsafe {
+            let mut wp = WINDOWPLACEMENT {
+                length: std::mem::size_of::<WINDOWPLACEMENT>() as _,
+                ..Default::default()
+            };
+            if !GetWindowPlacement(hwnd, &mut wp).as_bool() {
+                tx.send(Err(windows::core::Error::from_win32().into())).ok();
+                return;
+            }
+            let mut rc = wp.rcNormalPosition;
+            if GetWindowLongPtrW(hwnd, GWL_EXSTYLE) as u32 & WS_EX_TOOLWINDOW == 0 {
+                let current = MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST);
+                if let Some(info) = utility::monitor_info(current) {
+                    let dx = info.monitorInfo.rcWork.left - info.monitorInfo.rcMonitor.left;
+                    let dy = info.monitorInfo.rcWork.top - info.monitorInfo.rcMonitor.top;
+                    rc.left += dx;
+                    rc.right += dx;
+                    rc.top += dy;
+                    rc.bottom += dy;
+                }
+            }
+            let monitor = ctx.monitor_from_rect(&rc);
+            let show_state = match wp.showCmd {
+                SW_SHOWMINIMIZED => ShowState::Minimized,
+                SW_SHOWMAXIMIZED => ShowState::Maximized,
+                _ => ShowState::Normal,
+            };
+            tx.send(Ok(WindowPlacement {
+                position: Screen(Point::new(rc.left, rc.top)),
+                size: Physical(Size::new(
+                    (rc.right - rc.left) as _,
+                    (rc.bottom - rc.top) as _,
+                )),
+                show_state,
+                monitor: monitor.as_ref().map(|m| m.name.clone()),
+                monitor_rect: monitor.as_ref().map(|m| m.rect),
+                dpi: monitor.as_ref().map_or(DEFAULT_DPI as u32, |m| m.dpi),
+            }))
+            .ok();
+        });
+        rx.await?
+    }
+
     #[inline]
     pub async fn inner_size(&self) -> Result<Physical<Size<u32>>, Error> {
         let hwnd = self.hwnd.clone();
@@ -503,6 +968,18 @@ impl Window {
         Ok(rx.await?)
     }
 
+    pub async fn current_monitor(&self) -> Result<Monitor, Error> {
+        let hwnd = self.hwnd;
+        let (tx, rx) = oneshot::channel();
+        UiThread::post_with_context(move |ctx| {
+            let monitor = ctx
+                .monitor_from_rect(&utility::frame_rect(hwnd))
+                .ok_or_else(|| Error::Api(windows::core::Error::from_win32()));
+            tx.send(monitor).ok();
+        });
+        rx.await?
+    }
+
     #[inline]
     pub fn show(&self) {
         unsafe {
@@ -550,6 +1027,49 @@ impl Window {
         rx.await?
     }
 
+    #[inline]
+    pub async fn key_state(&self, key: VirtualKey) -> Result<ButtonState, Error> {
+        let hwnd = self.hwnd;
+        let (tx, rx) = oneshot::channel();
+        UiThread::post_with_context(move |ctx| unsafe {
+            let state = if ctx.get_window(hwnd).is_some() {
+                if GetKeyState(key as _) < 0 {
+                    Ok(ButtonState::Pressed)
+                } else {
+                    Ok(ButtonState::Released)
+                }
+            } else {
+                Err(Error::Closed)
+            };
+            tx.send(state).ok();
+        });
+        rx.await?
+    }
+
+    #[inline]
+    pub async fn keyboard_state(&self) -> Result<KeyboardState, Error> {
+        let hwnd = self.hwnd;
+        let (tx, rx) = oneshot::channel();
+        UiThread::post_with_context(move |ctx| unsafe {
+            let state = if ctx.get_window(hwnd).is_some() {
+                let mut state = [0u8; 256];
+                GetKeyboardState(state.as_mut_ptr())
+                    .ok()
+                    .map(|_| KeyboardState::new(state))
+                    .map_err(Error::from)
+            } else {
+                Err(Error::Closed)
+            };
+            tx.send(state).ok();
+        });
+        rx.await?
+    }
+
+    #[inline]
+    pub async fn mouse_buttons(&self) -> Result<MouseButtons, Error> {
+        Ok(self.keyboard_state().await?.mouse_buttons())
+    }
+
     #[inline]
     pub fn set_enable_ime(&self, enable: bool) {
         let hwnd = self.hwnd.clone();
@@ -578,6 +1098,145 @@ impl Window {
         });
     }
 
+    #[inline]
+    pub fn set_key_repeat(&self, flag: bool) {
+        let hwnd = self.hwnd;
+        UiThread::post_with_context(move |ctx| {
+            if let Some(mut window) = ctx.get_window_mut(hwnd) {
+                window.key_repeat = flag;
+            }
+        });
+    }
+
+    #[inline]
+    pub fn set_accelerators(&self, accelerators: AcceleratorTable) {
+        let hwnd = self.hwnd;
+        UiThread::post_with_context(move |ctx| {
+            if let Some(mut window) = ctx.get_window_mut(hwnd) {
+                window.accelerators = accelerators;
+            }
+        });
+    }
+
+    #[inline]
+    pub fn set_hit_test(&self, hit_test: impl HitTest + Send + 'static) {
+        let hwnd = self.hwnd;
+        UiThread::post_with_context(move |ctx| {
+            if let Some(mut window) = ctx.get_window_mut(hwnd) {
+                window.hit_test = Some(Box::new(hit_test));
+            }
+        });
+    }
+
+    #[inline]
+    pub fn clear_hit_test(&self) {
+        let hwnd = self.hwnd;
+        UiThread::post_with_context(move |ctx| {
+            if let Some(mut window) = ctx.get_window_mut(hwnd) {
+                window.hit_test = None;
+            }
+        });
+    }
+
+    #[inline]
+    pub fn set_shape(&self, region: Region) {
+        let hwnd = self.hwnd;
+        UiThread::post_with_context(move |ctx| unsafe {
+            let dpi = GetDpiForWindow(hwnd);
+            utility::set_window_shape(hwnd, Some(&region));
+            if let Some(mut window) = ctx.get_window_mut(hwnd) {
+                window.shape = Some(Shape {
+                    current: region.clone(),
+                    source: region,
+                    dpi,
+                });
+            }
+        });
+    }
+
+    #[inline]
+    pub fn clear_shape(&self) {
+        let hwnd = self.hwnd;
+        UiThread::post_with_context(move |ctx| {
+            if let Some(mut window) = ctx.get_window_mut(hwnd) {
+                window.shape = None;
+            }
+            utility::set_window_shape(hwnd, None);
+        });
+    }
+
+    #[inline]
+    pub fn set_dark_mode(&self, flag: bool) {
+        let hwnd = self.hwnd;
+        UiThread::post(move || appearance::set_dark_mode(hwnd, flag));
+    }
+
+    #[inline]
+    pub fn set_backdrop(&self, backdrop: Backdrop) {
+        let hwnd = self.hwnd;
+        UiThread::post_with_context(move |ctx| {
+            let mut frame_extended = match ctx.get_window(hwnd) {
+                Some(window) => window.frame_extended,
+                None => return,
+            };
+            appearance::set_backdrop(hwnd, backdrop, &mut frame_extended).ok();
+            if let Some(mut window) = ctx.get_window_mut(hwnd) {
+                window.frame_extended = frame_extended;
+            }
+        });
+    }
+
+    #[inline]
+    pub fn set_corner_preference(&self, corner_preference: CornerPreference) {
+        let hwnd = self.hwnd;
+        UiThread::post(move || appearance::set_corner_preference(hwnd, corner_preference));
+    }
+
+    #[inline]
+    pub fn set_caption_color(&self, color: impl Into<FrameColor>) {
+        let hwnd = self.hwnd;
+        let color = color.into();
+        UiThread::post(move || appearance::set_caption_color(hwnd, color));
+    }
+
+    #[inline]
+    pub fn set_border_color(&self, color: impl Into<FrameColor>) {
+        let hwnd = self.hwnd;
+        let color = color.into();
+        UiThread::post(move || appearance::set_border_color(hwnd, color));
+    }
+
+    #[inline]
+    pub fn set_text_color(&self, color: impl Into<FrameColor>) {
+        let hwnd = self.hwnd;
+        let color = color.into();
+        UiThread::post(move || appearance::set_text_color(hwnd, color));
+    }
+
+    #[inline]
+    pub fn begin_drag_move(&self) {
+        self.begin_drag(HTCAPTION);
+    }
+
+    #[inline]
+    pub fn begin_drag_resize(&self, edge: ResizeEdge) {
+        self.begin_drag(procedure::hit_area_to_value(edge.into()));
+    }
+
+    fn begin_drag(&self, value: u32) {
+        let hwnd = self.hwnd;
+        UiThread::post_with_context(move |ctx| unsafe {
+            if ctx.get_window(hwnd).is_some() {
+                PostMessageW(
+                    hwnd,
+                    procedure::WM_AWITA_DRAG,
+                    WPARAM(value as _),
+                    LPARAM(0),
+                );
+            }
+        });
+    }
+
     #[inline]
     pub async fn is_closed(&self) -> bool {
         let hwnd = self.hwnd.clone();
@@ -603,6 +1262,35 @@ impl Window {
         });
     }
 
+    pub async fn run_modal(&self, owner: &Window) -> Result<(), Error> {
+        let hwnd = self.hwnd;
+        let owner = owner.hwnd;
+        let (tx, rx) = oneshot::channel();
+        let (ended_tx, ended_rx) = oneshot::channel();
+        UiThread::post_with_context(move |ctx| unsafe {
+            let ret = match ctx.get_window_mut(hwnd) {
+                Some(mut window) => {
+                    window.modal = Some(Modal {
+                        owner,
+                        ended: ended_tx,
+                    });
+                    Ok(())
+                }
+                None => Err(Error::Closed),
+            };
+            if ret.is_ok() {
+                SetWindowLongPtrW(hwnd, GWLP_HWNDPARENT, owner.0);
+                EnableWindow(owner, false);
+                ShowWindow(hwnd, SW_SHOW);
+                SetActiveWindow(hwnd);
+            }
+            tx.send(ret).ok();
+        });
+        rx.await??;
+        ended_rx.await.ok();
+        Ok(())
+    }
+
     #[inline]
     pub fn raw_handle(&self) -> *mut std::ffi::c_void {
         self.hwnd.0 as _
@@ -663,11 +1351,21 @@ impl Window {
         self.on_event(|state| &state.key_input_channel).await
     }
 
+    #[inline]
+    pub async fn accelerator_receiver(&self) -> event::Receiver<u32> {
+        self.on_event(|state| &state.accelerator_channel).await
+    }
+
     #[inline]
     pub async fn char_input_receiver(&self) -> event::Receiver<char> {
         self.on_event(|state| &state.char_input_channel).await
     }
 
+    #[inline]
+    pub async fn text_input_receiver(&self) -> event::Receiver<String> {
+        self.on_event(|state| &state.text_input_channel).await
+    }
+
     #[inline]
     pub async fn ime_start_composition_receiver(&self) -> event::Receiver<()> {
         self.on_event(|state| &state.ime_start_composition_channel)
@@ -717,6 +1415,11 @@ impl Window {
         self.on_event(|state| &state.dpi_changed_channel).await
     }
 
+    #[inline]
+    pub async fn theme_changed_receiver(&self) -> event::Receiver<system::Theme> {
+        self.on_event(|state| &state.theme_changed_channel).await
+    }
+
     #[inline]
     pub async fn drop_files_receiver(&self) -> event::Receiver<event::DropFiles> {
         self.on_event(|state| &state.drop_files_channel).await